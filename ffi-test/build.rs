@@ -0,0 +1,9 @@
+fn main() {
+    println!("cargo:rerun-if-changed=test.c");
+    println!("cargo:rerun-if-changed=../include/stavec.h");
+    cc::Build::new()
+        .file("test.c")
+        .include("../include")
+        .warnings_into_errors(true)
+        .compile("stavec_ffi_test");
+}
@@ -0,0 +1,24 @@
+#![cfg(test)]
+
+use core::mem::MaybeUninit;
+use stavec::{ffi::StavecRaw, GenericVec, StaticVec};
+
+extern "C" {
+    fn c_bytes() -> *mut GenericVec<[MaybeUninit<u8>; 4]>;
+    fn c_sum(vec: *mut StavecRaw) -> i64;
+}
+
+#[test]
+fn c_owned() {
+    let bytes = unsafe { &mut *c_bytes() };
+    assert_eq!(bytes, &[1, 2, 3]);
+    bytes.push(4).unwrap();
+    assert!(bytes.is_full());
+}
+
+#[test]
+fn rust_owned() {
+    let mut v = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+    assert_eq!(unsafe { c_sum(&mut v.as_raw()) }, 16);
+    assert_eq!(v, [1, 2, 3, 10]);
+}
@@ -0,0 +1,36 @@
+#include <stavec.h>
+
+STAVEC_DEFINE(Bytes, uint8_t, 4);
+
+static Bytes bytes = {0};
+
+/* Buffer owned by C code. */
+Bytes *c_bytes(void) {
+    StavecRaw raw = STAVEC_RAW(bytes);
+    for (uint8_t i = 1; stavec_push_byte(&raw, i); ++i) {}
+    stavec_pop_byte(&raw);
+    return &bytes;
+}
+
+/* Pushes items to the vector owned by Rust code and sums them. */
+int64_t c_sum(StavecRaw *vec) {
+    if (stavec_capacity(vec) != 4 || vec->item_size != sizeof(int32_t)) {
+        return -1;
+    }
+    int32_t x = 10;
+    if (!stavec_push(vec, &x) || stavec_push(vec, &x)) {
+        return -1;
+    }
+    if (!stavec_pop(vec, &x) || x != 10 || !stavec_push(vec, &x)) {
+        return -1;
+    }
+    if (stavec_push_byte(vec, 0) || stavec_pop_byte(vec) != -1) {
+        return -1;
+    }
+    const int32_t *data = stavec_data(vec);
+    int64_t sum = 0;
+    for (size_t i = 0; i < stavec_len(vec); ++i) {
+        sum += data[i];
+    }
+    return sum;
+}
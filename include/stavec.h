@@ -0,0 +1,127 @@
+#ifndef STAVEC_H
+#define STAVEC_H
+
+/* Generated with cbindgen, do not edit manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* Declares a vector type with the same layout as `GenericVec<[MaybeUninit<T>; N]>` with `repr-c` feature. */
+#define STAVEC_DEFINE(name, type, cap) typedef struct { size_t len; type data[cap]; } name
+
+/* Creates a raw view of the vector declared by `STAVEC_DEFINE`. */
+#define STAVEC_RAW(vec) ((StavecRaw){ &(vec).len, (vec).data, sizeof((vec).data) / sizeof((vec).data[0]), sizeof((vec).data[0]) })
+
+/**
+ * Raw view of a vector for C code.
+ *
+ * Could be obtained by [`GenericVec::as_raw`].
+ *
+ * Because of `repr(C)` layout the vector `GenericVec<[MaybeUninit<T>; N]>` is the same as C structure
+ * `struct { size_t len; T data[N]; }`, so buffers could also be declared in C code (e.g. by `STAVEC_DEFINE` macro from `include/stavec.h`)
+ * and then accessed from Rust by casting the pointer.
+ *
+ * Only vectors of `Copy` items could be shared because C code doesn't call destructors.
+ */
+typedef struct StavecRaw {
+  /**
+   * Pointer to the vector length.
+   */
+  size_t *len;
+  /**
+   * Pointer to the first item.
+   */
+  void *data;
+  /**
+   * Capacity of the vector.
+   */
+  size_t capacity;
+  /**
+   * Size of a single item in bytes.
+   */
+  size_t item_size;
+} StavecRaw;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Number of items in the vector.
+ *
+ * # Safety
+ *
+ * `vec` must point to a valid view.
+ */
+size_t stavec_len(const StavecRaw *vec);
+
+/**
+ * Capacity of the vector.
+ *
+ * # Safety
+ *
+ * `vec` must point to a valid view.
+ */
+size_t stavec_capacity(const StavecRaw *vec);
+
+/**
+ * Pointer to the first item of the vector.
+ *
+ * # Safety
+ *
+ * `vec` must point to a valid view.
+ */
+void *stavec_data(const StavecRaw *vec);
+
+/**
+ * Copies an item to the end of the vector.
+ *
+ * Returns `false` if the vector is full.
+ *
+ * # Safety
+ *
+ * `vec` must point to a valid view, `item` must point to `item_size` readable bytes.
+ */
+bool stavec_push(StavecRaw *vec, const void *item);
+
+/**
+ * Removes the last item of the vector and copies it to `item` if it isn't null.
+ *
+ * Returns `false` if the vector is empty.
+ *
+ * # Safety
+ *
+ * `vec` must point to a valid view, `item` must be null or point to `item_size` writable bytes.
+ */
+bool stavec_pop(StavecRaw *vec, void *item);
+
+/**
+ * Appends a byte to the end of the byte vector.
+ *
+ * Returns `false` if the vector is full or its items are not bytes.
+ *
+ * # Safety
+ *
+ * `vec` must point to a valid view.
+ */
+bool stavec_push_byte(StavecRaw *vec, uint8_t byte);
+
+/**
+ * Removes and returns the last byte of the byte vector.
+ *
+ * Returns `-1` if the vector is empty or its items are not bytes.
+ *
+ * # Safety
+ *
+ * `vec` must point to a valid view.
+ */
+int32_t stavec_pop_byte(StavecRaw *vec);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* STAVEC_H */
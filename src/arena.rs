@@ -0,0 +1,233 @@
+use core::{
+    alloc::Layout,
+    cell::{Cell, UnsafeCell},
+    fmt,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    ptr,
+};
+
+/// Record about a value that should be dropped on arena reset.
+///
+/// Stored in the arena memory right before the value.
+#[derive(Clone, Copy)]
+struct DropEntry {
+    drop: unsafe fn(*mut u8),
+    /// Offset of the value.
+    value: usize,
+    /// Offset of the previous entry.
+    prev: Option<usize>,
+}
+
+/// Drops a value that may be unaligned because the arena was moved after allocation.
+unsafe fn drop_value<T>(ptr: *mut u8) {
+    mem::drop(ptr::read_unaligned(ptr as *const T));
+}
+
+/// Bump allocator over `N` bytes of static memory.
+///
+/// Values of different types could be allocated in the arena while it is borrowed,
+/// all of them are freed at once on [`reset`](`Self::reset`) or when the arena is dropped.
+/// Values which need drop are dropped in reverse order of allocation.
+///
+/// Lifetime `'a` bounds the allocated values, it is usually inferred.
+pub struct StaticArena<'a, const N: usize> {
+    /// Number of used bytes.
+    len: Cell<usize>,
+    /// Offset of the last drop entry.
+    last: Cell<Option<usize>>,
+    /// Arena is invariant over `'a` and doesn't implement `Send` because it may own values of any types.
+    _ghost: PhantomData<(Cell<&'a ()>, *mut ())>,
+    data: UnsafeCell<[MaybeUninit<u8>; N]>,
+}
+
+impl<const N: usize> StaticArena<'_, N> {
+    /// Create a new empty arena.
+    pub fn new() -> Self {
+        Self {
+            len: Cell::new(0),
+            last: Cell::new(None),
+            _ghost: PhantomData,
+            data: UnsafeCell::new([MaybeUninit::uninit(); N]),
+        }
+    }
+}
+
+impl<const N: usize> Default for StaticArena<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const N: usize> StaticArena<'a, N> {
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Number of used bytes including alignment padding and drop entries.
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    /// Checks whether nothing is allocated.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of free bytes.
+    ///
+    /// Values may not fit even if their size is less than this number because of alignment.
+    pub fn remaining(&self) -> usize {
+        N - self.len()
+    }
+
+    fn base(&self) -> *mut u8 {
+        self.data.get() as *mut u8
+    }
+
+    /// Reserves memory for `layout` and returns its offset.
+    fn bump(&self, layout: Layout) -> Option<usize> {
+        let len = self.len.get();
+        let start =
+            len.checked_add(unsafe { self.base().add(len) }.align_offset(layout.align()))?;
+        let end = start.checked_add(layout.size())?;
+        if end > N {
+            return None;
+        }
+        self.len.set(end);
+        Some(start)
+    }
+
+    /// Allocates memory for `layout`.
+    ///
+    /// Returns `None` if there is not enough space.
+    pub fn alloc_layout(&self, layout: Layout) -> Option<ptr::NonNull<u8>> {
+        let offset = self.bump(layout)?;
+        ptr::NonNull::new(unsafe { self.base().add(offset) })
+    }
+
+    /// Moves a value into the arena and returns a reference to it.
+    ///
+    /// If there is not enough space then the value is returned back.
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc<T: 'a>(&self, value: T) -> Result<&mut T, T> {
+        let len = self.len.get();
+        let entry = if mem::needs_drop::<T>() {
+            match self.bump(Layout::new::<DropEntry>()) {
+                Some(offset) => Some(offset),
+                None => return Err(value),
+            }
+        } else {
+            None
+        };
+        let offset = match self.bump(Layout::new::<T>()) {
+            Some(offset) => offset,
+            None => {
+                self.len.set(len);
+                return Err(value);
+            }
+        };
+        let ptr = unsafe { self.base().add(offset) } as *mut T;
+        unsafe { ptr.write(value) };
+        if let Some(entry) = entry {
+            unsafe {
+                (self.base().add(entry) as *mut DropEntry).write(DropEntry {
+                    drop: drop_value::<T>,
+                    value: offset,
+                    prev: self.last.get(),
+                })
+            };
+            self.last.set(Some(entry));
+        }
+        Ok(unsafe { &mut *ptr })
+    }
+
+    /// Moves a value into the arena and returns a reference to it.
+    ///
+    /// *Panics if there is not enough space.*
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: 'a>(&self, value: T) -> &mut T {
+        match self.try_alloc(value) {
+            Ok(value) => value,
+            Err(_) => panic!("arena capacity exceeded"),
+        }
+    }
+
+    /// Drops all allocated values and frees the memory.
+    pub fn reset(&mut self) {
+        let guard = ResetGuard(self);
+        let base = guard.0.data.get_mut().as_mut_ptr() as *mut u8;
+        while let Some(offset) = guard.0.last.get() {
+            let entry = unsafe { (base.add(offset) as *const DropEntry).read_unaligned() };
+            // Entry is removed before drop, so the value is not dropped twice if drop panics.
+            guard.0.last.set(entry.prev);
+            unsafe { (entry.drop)(base.add(entry.value)) };
+        }
+        guard.0.len.set(0);
+        mem::forget(guard);
+    }
+}
+
+/// Continues resetting if a value panics on drop.
+struct ResetGuard<'r, 'a, const N: usize>(&'r mut StaticArena<'a, N>);
+
+impl<const N: usize> Drop for ResetGuard<'_, '_, N> {
+    fn drop(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl<const N: usize> Drop for StaticArena<'_, N> {
+    fn drop(&mut self) {
+        self.reset();
+    }
+}
+
+impl<const N: usize> fmt::Debug for StaticArena<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("StaticArena")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// Memory is freed only on arena reset, except for the last allocation which could be deallocated or resized in place.
+#[cfg(feature = "allocator-api2")]
+unsafe impl<const N: usize> allocator_api2::alloc::Allocator for &StaticArena<'_, N> {
+    fn allocate(
+        &self,
+        layout: Layout,
+    ) -> Result<ptr::NonNull<[u8]>, allocator_api2::alloc::AllocError> {
+        let ptr = self
+            .alloc_layout(layout)
+            .ok_or(allocator_api2::alloc::AllocError)?;
+        Ok(ptr::NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: ptr::NonNull<u8>, layout: Layout) {
+        let offset = ptr.as_ptr() as usize - self.base() as usize;
+        if offset + layout.size() == self.len.get() {
+            self.len.set(offset);
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: ptr::NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<ptr::NonNull<[u8]>, allocator_api2::alloc::AllocError> {
+        let offset = ptr.as_ptr() as usize - self.base() as usize;
+        if offset + old_layout.size() == self.len.get()
+            && ptr.as_ptr().align_offset(new_layout.align()) == 0
+            && offset + new_layout.size() <= N
+        {
+            self.len.set(offset + new_layout.size());
+            return Ok(ptr::NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+        let new_ptr = self.allocate(new_layout)?;
+        ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr() as *mut u8, old_layout.size());
+        Ok(new_ptr)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{
+    error::AsciiError,
+    traits::{Container, DefaultContainer, Length},
+    GenericString, GenericVec,
+};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, Index},
+    str::from_utf8_unchecked,
+};
+
+fn check_ascii(bytes: &[u8]) -> Result<(), AsciiError> {
+    match bytes.iter().position(|b| !b.is_ascii()) {
+        Some(index) => Err(AsciiError::NotAscii(index)),
+        None => Ok(()),
+    }
+}
+
+/// String that contains only ASCII characters.
+///
+/// Every byte is a character, so the string could be indexed by bytes without boundary checks.
+#[repr(transparent)]
+pub struct GenericAsciiString<C: Container<Item = u8> + ?Sized, L: Length = usize> {
+    bytes: GenericVec<C, L>,
+}
+
+/// ASCII string with static capacity of `N` bytes.
+pub type StaticAsciiString<const N: usize> = GenericAsciiString<[u8; N]>;
+
+impl<C: DefaultContainer<Item = u8>, L: Length> GenericAsciiString<C, L> {
+    /// Create a new empty string.
+    pub fn new() -> Self {
+        Self {
+            bytes: GenericVec::new(),
+        }
+    }
+
+    /// Creates a string from bytes checking that they are ASCII.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, AsciiError> {
+        let mut self_ = Self::new();
+        self_.push_bytes(bytes)?;
+        Ok(self_)
+    }
+}
+
+impl<C: DefaultContainer<Item = u8>, L: Length> Default for GenericAsciiString<C, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<C: DefaultContainer<Item = u8>, L: Length> Clone for GenericAsciiString<C, L> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+impl<C: DefaultContainer<Item = u8>, L: Length> TryFrom<&str> for GenericAsciiString<C, L> {
+    type Error = AsciiError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(s.as_bytes())
+    }
+}
+impl<C: DefaultContainer<Item = u8>, L: Length> TryFrom<&[u8]> for GenericAsciiString<C, L> {
+    type Error = AsciiError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(bytes)
+    }
+}
+
+impl<C: Container<Item = u8>, L: Length> GenericAsciiString<C, L> {
+    /// Creates a string from the vector checking that it contains only ASCII.
+    pub fn try_from_vec(vec: GenericVec<C, L>) -> Result<Self, AsciiError> {
+        check_ascii(vec.as_slice())?;
+        Ok(Self { bytes: vec })
+    }
+    /// Converts the string into underlying vector.
+    pub fn into_bytes(self) -> GenericVec<C, L> {
+        self.bytes
+    }
+    /// Converts the string into UTF-8 string with the same container.
+    pub fn into_string(self) -> GenericString<C, L> {
+        unsafe { GenericString::from_utf8_unchecked(self.bytes) }
+    }
+}
+
+impl<C: Container<Item = u8>, L: Length> TryFrom<GenericVec<C, L>> for GenericAsciiString<C, L> {
+    type Error = AsciiError;
+
+    fn try_from(vec: GenericVec<C, L>) -> Result<Self, Self::Error> {
+        Self::try_from_vec(vec)
+    }
+}
+impl<C: Container<Item = u8>, L: Length> TryFrom<GenericString<C, L>> for GenericAsciiString<C, L> {
+    type Error = AsciiError;
+
+    fn try_from(s: GenericString<C, L>) -> Result<Self, Self::Error> {
+        Self::try_from_vec(s.into_bytes())
+    }
+}
+impl<C: Container<Item = u8>, L: Length> From<GenericAsciiString<C, L>> for GenericString<C, L> {
+    fn from(s: GenericAsciiString<C, L>) -> Self {
+        s.into_string()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> GenericAsciiString<C, L> {
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+    pub fn remaining(&self) -> usize {
+        self.bytes.remaining()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+    pub fn is_full(&self) -> bool {
+        self.bytes.is_full()
+    }
+
+    /// Provides an access to underlying vector.
+    pub fn as_vec(&self) -> &GenericVec<C, L> {
+        &self.bytes
+    }
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+    /// String slice, no validation is performed.
+    pub fn as_str(&self) -> &str {
+        unsafe { from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+
+    /// Returns byte at `index` or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<u8> {
+        self.as_bytes().get(index).copied()
+    }
+    /// Replaces byte at `index`.
+    ///
+    /// *Panics if `index` is out of bounds.*
+    pub fn set(&mut self, index: usize, byte: u8) -> Result<(), AsciiError> {
+        check_ascii(&[byte])?;
+        self.bytes[index] = byte;
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+    pub fn truncate(&mut self, new_len: usize) {
+        self.bytes.truncate(new_len);
+    }
+
+    pub fn push(&mut self, byte: u8) -> Result<(), AsciiError> {
+        check_ascii(&[byte])?;
+        self.bytes.push(byte).map_err(|_| AsciiError::Full)
+    }
+    /// Appends bytes to the string.
+    ///
+    /// If bytes are not ASCII or don't fit then nothing is appended.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), AsciiError> {
+        check_ascii(bytes)?;
+        Ok(self.bytes.push_slice(bytes)?)
+    }
+    /// Appends string slice to the string.
+    ///
+    /// If the slice is not ASCII or doesn't fit then nothing is appended.
+    pub fn push_str(&mut self, s: &str) -> Result<(), AsciiError> {
+        self.push_bytes(s.as_bytes())
+    }
+    pub fn pop(&mut self) -> Option<u8> {
+        self.bytes.pop()
+    }
+
+    /// Checks that strings are equal ignoring ASCII case.
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
+    }
+    /// Compares strings ignoring ASCII case.
+    pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+        let lower = |b: &u8| b.to_ascii_lowercase();
+        self.as_bytes()
+            .iter()
+            .map(lower)
+            .cmp(other.as_bytes().iter().map(lower))
+    }
+
+    pub fn make_ascii_uppercase(&mut self) {
+        self.bytes.make_ascii_uppercase();
+    }
+    pub fn make_ascii_lowercase(&mut self) {
+        self.bytes.make_ascii_lowercase();
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Index<usize> for GenericAsciiString<C, L> {
+    type Output = u8;
+
+    fn index(&self, index: usize) -> &u8 {
+        &self.as_bytes()[index]
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Hash for GenericAsciiString<C, L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> fmt::Debug for GenericAsciiString<C, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> fmt::Display for GenericAsciiString<C, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// Fails if the string is not ASCII or doesn't fit, nothing is written in that case.
+impl<C: Container<Item = u8> + ?Sized, L: Length> fmt::Write for GenericAsciiString<C, L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Deref for GenericAsciiString<C, L> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> AsRef<str> for GenericAsciiString<C, L> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> AsRef<[u8]> for GenericAsciiString<C, L> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Borrow<str> for GenericAsciiString<C, L> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> PartialEq<str> for GenericAsciiString<C, L> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> PartialEq<&str> for GenericAsciiString<C, L> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length, D: Container<Item = u8> + ?Sized, M: Length>
+    PartialEq<GenericAsciiString<D, M>> for GenericAsciiString<C, L>
+{
+    fn eq(&self, other: &GenericAsciiString<D, M>) -> bool {
+        self.as_bytes().eq(other.as_bytes())
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Eq for GenericAsciiString<C, L> {}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length, D: Container<Item = u8> + ?Sized, M: Length>
+    PartialOrd<GenericAsciiString<D, M>> for GenericAsciiString<C, L>
+{
+    fn partial_cmp(&self, other: &GenericAsciiString<D, M>) -> Option<Ordering> {
+        self.as_bytes().partial_cmp(other.as_bytes())
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Ord for GenericAsciiString<C, L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    sync::{AtomicBool, AtomicUsize, Ordering, SlotTracker},
+    utils::slice_assume_init_mut,
+    StaticVec,
+};
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    mem::{self, MaybeUninit},
+    ptr,
+};
+
+/// Append-only vector with static capacity which could be pushed to concurrently.
+///
+/// Each push reserves an index by atomic increment of length, writes the item and then marks the slot as published.
+/// Readers see only published items, so items may be observed in different order than pushes were called.
+///
+/// Available only on targets that support atomic compare-and-swap operations.
+pub struct AtomicStaticVec<T, const N: usize> {
+    /// Number of reserved slots. May exceed `N` when the vector is full.
+    reserved: AtomicUsize,
+    /// Whether an item in corresponding slot is written and could be read.
+    published: [AtomicBool; N],
+    tracker: SlotTracker,
+    data: UnsafeCell<[MaybeUninit<T>; N]>,
+}
+
+unsafe impl<T: Send + Sync, const N: usize> Sync for AtomicStaticVec<T, N> {}
+
+impl<T, const N: usize> AtomicStaticVec<T, N> {
+    /// Create a new empty vector.
+    ///
+    /// Could be used to initialize a `static`.
+    #[cfg(not(loom))]
+    pub const fn new() -> Self {
+        Self {
+            reserved: AtomicUsize::new(0),
+            published: [const { AtomicBool::new(false) }; N],
+            tracker: SlotTracker::new(N),
+            data: UnsafeCell::new([const { MaybeUninit::uninit() }; N]),
+        }
+    }
+    /// Create a new empty vector.
+    #[cfg(loom)]
+    pub fn new() -> Self {
+        Self {
+            reserved: AtomicUsize::new(0),
+            published: [(); N].map(|()| AtomicBool::new(false)),
+            tracker: SlotTracker::new(N),
+            data: UnsafeCell::new([(); N].map(|()| MaybeUninit::uninit())),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of reserved items, some of them may be not published yet.
+    pub fn len(&self) -> usize {
+        self.reserved.load(Ordering::Acquire).min(N)
+    }
+
+    /// Checks whether the vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks whether the vector is full.
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    fn slot_ptr(&self, index: usize) -> *mut MaybeUninit<T> {
+        debug_assert!(index < N);
+        unsafe { (self.data.get() as *mut MaybeUninit<T>).add(index) }
+    }
+
+    /// Appends a new item to the end of the vector.
+    ///
+    /// If the vector is already full then the item is returned.
+    pub fn push(&self, value: T) -> Result<(), T> {
+        // Check first to limit the growth of the counter when the vector is full.
+        if self.reserved.load(Ordering::Relaxed) >= N {
+            return Err(value);
+        }
+        let index = self.reserved.fetch_add(1, Ordering::Relaxed);
+        if index >= N {
+            return Err(value);
+        }
+        self.tracker.write(index, || unsafe {
+            ptr::write(self.slot_ptr(index), MaybeUninit::new(value))
+        });
+        self.published[index].store(true, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns a reference to the item at `index` if it is published.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < N && self.published[index].load(Ordering::Acquire) {
+            Some(self.tracker.read(index, || unsafe {
+                (*self.slot_ptr(index)).assume_init_ref()
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns iterator over references of published items in index order.
+    ///
+    /// Items which are reserved but not published yet are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// Slice of the vector content.
+    ///
+    /// Exclusive access guarantees that all pushes are complete, so all reserved items are published.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        unsafe { slice_assume_init_mut(&mut self.data.get_mut()[..len]) }
+    }
+
+    /// Converts into an ordinary vector.
+    pub fn into_static_vec(mut self) -> StaticVec<T, N> {
+        let len = self.len();
+        let data = mem::replace(self.data.get_mut(), [(); N].map(|()| MaybeUninit::uninit()));
+        self.reserved.store(0, Ordering::Relaxed);
+        unsafe { StaticVec::from_raw_parts(data, len) }
+    }
+}
+
+impl<T, const N: usize> Default for AtomicStaticVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for AtomicStaticVec<T, N> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for AtomicStaticVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
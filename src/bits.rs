@@ -0,0 +1,297 @@
+use crate::traits::{BitWord, Container, DefaultContainer, Length};
+use core::{
+    fmt,
+    mem::size_of,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign},
+};
+use num_traits::{clamp_max, One, PrimInt, Zero};
+
+/// Fixed-capacity vector of bits packed into words.
+///
+/// The type parametrized by:
+/// + `C` - container of words (unsigned integers), may be unsized.
+/// + `L` - type of the length of the vector in bits.
+///
+/// Bits beyond the vector length are always zero.
+#[cfg_attr(feature = "repr-c", repr(C))]
+pub struct GenericBitVec<C: Container + ?Sized, L: Length = usize>
+where
+    C::Slot: BitWord,
+{
+    len: L,
+    words: C,
+}
+
+/// Bit vector stored in `BYTES` bytes, so its capacity is `8 * BYTES` bits.
+///
+/// Note that the parameter is a size in bytes, not in bits, e.g. `StaticBitVec<8>` holds 64 bits.
+pub type StaticBitVec<const BYTES: usize> = GenericBitVec<[u8; BYTES]>;
+
+impl<C: DefaultContainer, L: Length> GenericBitVec<C, L>
+where
+    C::Slot: BitWord,
+{
+    /// Create a new empty vector.
+    pub fn new() -> Self {
+        Self {
+            len: L::zero(),
+            words: C::default(),
+        }
+    }
+}
+
+impl<C: DefaultContainer, L: Length> Default for GenericBitVec<C, L>
+where
+    C::Slot: BitWord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: DefaultContainer, L: Length> Clone for GenericBitVec<C, L>
+where
+    C::Slot: BitWord,
+{
+    fn clone(&self) -> Self {
+        let mut self_ = Self::new();
+        self_.words.as_mut().copy_from_slice(self.words.as_ref());
+        self_.len = self.len;
+        self_
+    }
+}
+
+impl<C: Container + ?Sized, L: Length> GenericBitVec<C, L>
+where
+    C::Slot: BitWord,
+{
+    const WORD_BITS: usize = 8 * size_of::<C::Slot>();
+
+    fn locate(index: usize) -> (usize, C::Slot) {
+        (
+            index / Self::WORD_BITS,
+            C::Slot::one() << (index % Self::WORD_BITS),
+        )
+    }
+
+    /// Storage words.
+    pub fn words(&self) -> &[C::Slot] {
+        self.words.as_ref()
+    }
+
+    pub fn capacity(&self) -> usize {
+        clamp_max(
+            self.words.as_ref().len() * Self::WORD_BITS,
+            L::max_value().to_usize().unwrap(),
+        )
+    }
+
+    /// The number of bits in the vector.
+    pub fn len(&self) -> usize {
+        self.len.to_usize().unwrap()
+    }
+
+    /// Number of remaining free bits in the vector.
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    /// Checks whether the vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len.is_zero()
+    }
+
+    /// Checks whether the vector is full.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Returns the bit at `index` or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len() {
+            let (word, mask) = Self::locate(index);
+            Some(!(self.words.as_ref()[word] & mask).is_zero())
+        } else {
+            None
+        }
+    }
+
+    /// Sets the bit at `index`.
+    ///
+    /// *Panics if `index` is out of bounds.*
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len());
+        let (word, mask) = Self::locate(index);
+        let word = &mut self.words.as_mut()[word];
+        if value {
+            *word = *word | mask;
+        } else {
+            *word = *word & !mask;
+        }
+    }
+
+    /// Appends a new bit to the end of the vector.
+    ///
+    /// If the vector is already full then the bit is returned.
+    pub fn push(&mut self, value: bool) -> Result<(), bool> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.len += L::one();
+        self.set(self.len() - 1, value);
+        Ok(())
+    }
+
+    /// Removes and returns the last bit of the vector.
+    ///
+    /// If the vector is empty then `None` is returned.
+    pub fn pop(&mut self) -> Option<bool> {
+        let value = self.get(self.len().checked_sub(1)?)?;
+        self.set(self.len() - 1, false);
+        self.len -= L::one();
+        Some(value)
+    }
+
+    /// Truncates the vector.
+    ///
+    /// If `new_len` is greater then vector length the methods simply does nothing.
+    pub fn truncate(&mut self, new_len: usize) {
+        while self.len() > new_len {
+            self.pop();
+        }
+    }
+
+    /// Removes all bits from the vector.
+    pub fn clear(&mut self) {
+        for word in self.words.as_mut() {
+            *word = C::Slot::zero();
+        }
+        self.len = L::zero();
+    }
+
+    /// Returns iterator over bits.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len()).map(move |i| self.get(i).unwrap())
+    }
+
+    /// Returns iterator over indices of set bits.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .as_ref()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, word)| {
+                let mut word = *word;
+                core::iter::from_fn(move || {
+                    if word.is_zero() {
+                        None
+                    } else {
+                        let bit = word.trailing_zeros() as usize;
+                        word = word & (word - C::Slot::one());
+                        Some(i * Self::WORD_BITS + bit)
+                    }
+                })
+            })
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .as_ref()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Number of unset bits.
+    pub fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+
+    /// Applies `op` to each pair of words of this and `other` vectors.
+    ///
+    /// Length of the resulting vector is the maximum of lengths, missing bits are treated as zeros.
+    fn combine<D, M, F>(&mut self, other: &GenericBitVec<D, M>, op: F)
+    where
+        D: Container<Slot = C::Slot> + ?Sized,
+        M: Length,
+        F: Fn(C::Slot, C::Slot) -> C::Slot,
+    {
+        assert!(other.len() <= self.capacity());
+        let zero = C::Slot::zero();
+        let others = other.words().iter().chain(core::iter::repeat(&zero));
+        for (word, other) in self.words.as_mut().iter_mut().zip(others) {
+            *word = op(*word, *other);
+        }
+        self.len = L::from_usize(self.len().max(other.len())).unwrap();
+        // Clear bits beyond length.
+        let len = self.len();
+        let (first, mask) = Self::locate(len);
+        if let Some(word) = self.words.as_mut().get_mut(first) {
+            *word = *word & (mask - C::Slot::one());
+        }
+        for word in self.words.as_mut().iter_mut().skip(first + 1) {
+            *word = zero;
+        }
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        /// *Panics if `rhs` length is greater than `self` capacity.*
+        impl<C: Container + ?Sized, L: Length, D: Container<Slot = C::Slot> + ?Sized, M: Length>
+            $assign_trait<&GenericBitVec<D, M>> for GenericBitVec<C, L>
+        where
+            C::Slot: BitWord,
+        {
+            fn $assign_method(&mut self, rhs: &GenericBitVec<D, M>) {
+                self.combine(rhs, |a, b| a $op b);
+            }
+        }
+
+        /// *Panics if `rhs` length is greater than `self` capacity.*
+        impl<C: DefaultContainer, L: Length, D: Container<Slot = C::Slot> + ?Sized, M: Length>
+            $trait<&GenericBitVec<D, M>> for &GenericBitVec<C, L>
+        where
+            C::Slot: BitWord,
+        {
+            type Output = GenericBitVec<C, L>;
+
+            fn $method(self, rhs: &GenericBitVec<D, M>) -> Self::Output {
+                let mut result = self.clone();
+                result.combine(rhs, |a, b| a $op b);
+                result
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl<C: Container + ?Sized, L: Length, D: Container + ?Sized, M: Length>
+    PartialEq<GenericBitVec<D, M>> for GenericBitVec<C, L>
+where
+    C::Slot: BitWord,
+    D::Slot: BitWord,
+{
+    fn eq(&self, other: &GenericBitVec<D, M>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<C: Container + ?Sized, L: Length> Eq for GenericBitVec<C, L> where C::Slot: BitWord {}
+
+/// Formats the vector as a string of `0` and `1`, the first bit goes first.
+impl<C: Container + ?Sized, L: Length> fmt::Debug for GenericBitVec<C, L>
+where
+    C::Slot: BitWord,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+#[cfg(feature = "repr-c")]
+use crate::error::BytesError;
+#[cfg(any(feature = "repr-c", feature = "bytemuck"))]
+use crate::{
+    traits::{Container, Length},
+    GenericVec,
+};
+#[cfg(feature = "repr-c")]
+use core::{
+    mem::{self, size_of, MaybeUninit},
+    ptr, slice,
+};
+
+/// Zeroed vector is a valid empty vector.
+#[cfg(feature = "bytemuck")]
+unsafe impl<C: Container + bytemuck::Zeroable, L: Length + bytemuck::Zeroable> bytemuck::Zeroable
+    for GenericVec<C, L>
+{
+}
+
+#[cfg(feature = "repr-c")]
+impl<C: Container, L: Length> GenericVec<C, L> {
+    const NO_PADDING: () = assert!(
+        size_of::<Self>() == size_of::<C>() + size_of::<L>(),
+        "vector layout must not contain padding",
+    );
+
+    /// # Safety
+    ///
+    /// `C` and `L` must contain no padding and no interior mutability.
+    unsafe fn as_bytes_unchecked(&self) -> &[u8] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NO_PADDING;
+        slice::from_raw_parts(self as *const Self as *const u8, size_of::<Self>())
+    }
+
+    fn check_bytes(bytes: &[u8]) -> Result<(), BytesError> {
+        if bytes.len() != size_of::<Self>() {
+            Err(BytesError::Size)
+        } else if !(bytes.as_ptr() as *const Self).is_aligned() {
+            Err(BytesError::Alignment)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Ensures that the vector read from bytes doesn't violate length invariant.
+    fn check_len(&self) -> Result<(), BytesError> {
+        if self.len <= L::from_usize(self.capacity()).unwrap() {
+            Ok(())
+        } else {
+            Err(BytesError::Length)
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Any bit pattern must be valid for `C` and `L`, they must contain no interior mutability.
+    unsafe fn ref_from_bytes_unchecked(bytes: &[u8]) -> Result<&Self, BytesError> {
+        Self::check_bytes(bytes)?;
+        let self_ = &*(bytes.as_ptr() as *const Self);
+        self_.check_len()?;
+        Ok(self_)
+    }
+
+    /// # Safety
+    ///
+    /// Any bit pattern must be valid for `C` and `L`, they must contain no padding and no interior mutability.
+    unsafe fn mut_from_bytes_unchecked(bytes: &mut [u8]) -> Result<&mut Self, BytesError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NO_PADDING;
+        Self::check_bytes(bytes)?;
+        let self_ = &mut *(bytes.as_mut_ptr() as *mut Self);
+        self_.check_len()?;
+        Ok(self_)
+    }
+
+    /// # Safety
+    ///
+    /// Any bit pattern must be valid for `C` and `L`.
+    unsafe fn read_from_bytes_unchecked(bytes: &[u8]) -> Result<Self, BytesError> {
+        if bytes.len() != size_of::<Self>() {
+            return Err(BytesError::Size);
+        }
+        let mut self_ = MaybeUninit::<Self>::uninit();
+        ptr::copy_nonoverlapping(bytes.as_ptr(), self_.as_mut_ptr() as *mut u8, bytes.len());
+        let self_ = self_.assume_init();
+        match self_.check_len() {
+            Ok(()) => Ok(self_),
+            Err(err) => {
+                // Vector is invalid so we must not drop it.
+                mem::forget(self_);
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Conversions to and from bytes using [`bytemuck`] traits.
+///
+/// *Compilation fails if the vector layout contains padding, e.g. when length alignment is lower than container alignment.*
+#[cfg(all(feature = "repr-c", feature = "bytemuck"))]
+impl<C: Container + bytemuck::Pod, L: Length + bytemuck::Pod> GenericVec<C, L> {
+    /// Byte representation of the vector, including un-initialized part of container.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { self.as_bytes_unchecked() }
+    }
+
+    /// Interprets bytes as a vector.
+    ///
+    /// Bytes must have exact size and alignment of the vector, and stored length must not exceed the capacity.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<&Self, BytesError> {
+        unsafe { Self::ref_from_bytes_unchecked(bytes) }
+    }
+
+    /// Interprets mutable bytes as a vector.
+    ///
+    /// Bytes must have exact size and alignment of the vector, and stored length must not exceed the capacity.
+    pub fn try_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, BytesError> {
+        unsafe { Self::mut_from_bytes_unchecked(bytes) }
+    }
+
+    /// Reads a vector from possibly unaligned bytes.
+    pub fn try_pod_read_unaligned(bytes: &[u8]) -> Result<Self, BytesError> {
+        unsafe { Self::read_from_bytes_unchecked(bytes) }
+    }
+}
+
+/// Conversions to and from bytes using [`zerocopy`] traits.
+///
+/// *Compilation fails if the vector layout contains padding, e.g. when length alignment is lower than container alignment.*
+#[cfg(all(feature = "repr-c", feature = "zerocopy"))]
+impl<C, L> GenericVec<C, L>
+where
+    C: Container + zerocopy::FromBytes + zerocopy::IntoBytes + zerocopy::Immutable,
+    L: Length + zerocopy::FromBytes + zerocopy::IntoBytes + zerocopy::Immutable,
+{
+    /// Byte representation of the vector, including un-initialized part of container.
+    ///
+    /// If `bytemuck` feature is also enabled then the method with `bytemuck` bounds is used instead.
+    #[cfg(not(feature = "bytemuck"))]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { self.as_bytes_unchecked() }
+    }
+
+    /// Interprets bytes as a vector.
+    ///
+    /// Bytes must have exact size and alignment of the vector, and stored length must not exceed the capacity.
+    pub fn try_ref_from_bytes(bytes: &[u8]) -> Result<&Self, BytesError> {
+        unsafe { Self::ref_from_bytes_unchecked(bytes) }
+    }
+
+    /// Interprets mutable bytes as a vector.
+    ///
+    /// Bytes must have exact size and alignment of the vector, and stored length must not exceed the capacity.
+    pub fn try_mut_from_bytes(bytes: &mut [u8]) -> Result<&mut Self, BytesError> {
+        unsafe { Self::mut_from_bytes_unchecked(bytes) }
+    }
+
+    /// Reads a vector from possibly unaligned bytes.
+    pub fn try_read_from_bytes(bytes: &[u8]) -> Result<Self, BytesError> {
+        unsafe { Self::read_from_bytes_unchecked(bytes) }
+    }
+}
@@ -1,14 +1,15 @@
 use crate::{
     traits::{Container, Length},
-    GenericVec,
+    GenericString, GenericVec,
 };
 use core::cmp::Ordering;
 
-impl<C: Container + ?Sized, L: Length> PartialOrd for GenericVec<C, L>
+impl<C: Container + ?Sized, L: Length, D: Container<Item = C::Item> + ?Sized, M: Length>
+    PartialOrd<GenericVec<D, M>> for GenericVec<C, L>
 where
     C::Item: PartialOrd,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &GenericVec<D, M>) -> Option<Ordering> {
         self.as_slice().partial_cmp(other.as_slice())
     }
 }
@@ -22,11 +23,12 @@ where
     }
 }
 
-impl<C: Container + ?Sized, L: Length> PartialEq for GenericVec<C, L>
+impl<C: Container + ?Sized, L: Length, D: Container + ?Sized, M: Length> PartialEq<GenericVec<D, M>>
+    for GenericVec<C, L>
 where
-    C::Item: PartialEq,
+    C::Item: PartialEq<D::Item>,
 {
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &GenericVec<D, M>) -> bool {
         self.as_slice().eq(other.as_slice())
     }
 }
@@ -78,3 +80,60 @@ where
         self.as_slice().eq(other.as_slice())
     }
 }
+
+// Strings are compared as `str`, so that comparison is consistent with `Hash` and `Borrow<str>`.
+
+impl<C: Container<Item = u8> + ?Sized, L: Length, D: Container<Item = u8> + ?Sized, M: Length>
+    PartialEq<GenericString<D, M>> for GenericString<C, L>
+{
+    fn eq(&self, other: &GenericString<D, M>) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Eq for GenericString<C, L> {}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length, D: Container<Item = u8> + ?Sized, M: Length>
+    PartialOrd<GenericString<D, M>> for GenericString<C, L>
+{
+    fn partial_cmp(&self, other: &GenericString<D, M>) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Ord for GenericString<C, L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+macro_rules! impl_str_cmp {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl<C: Container<Item = u8> + ?Sized, L: Length> PartialEq<$t> for GenericString<C, L> {
+                fn eq(&self, other: &$t) -> bool {
+                    self.as_str().eq(&other[..])
+                }
+            }
+            impl<C: Container<Item = u8> + ?Sized, L: Length> PartialEq<GenericString<C, L>> for $t {
+                fn eq(&self, other: &GenericString<C, L>) -> bool {
+                    self[..].eq(other.as_str())
+                }
+            }
+            impl<C: Container<Item = u8> + ?Sized, L: Length> PartialOrd<$t> for GenericString<C, L> {
+                fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                    self.as_str().partial_cmp(&other[..])
+                }
+            }
+            impl<C: Container<Item = u8> + ?Sized, L: Length> PartialOrd<GenericString<C, L>> for $t {
+                fn partial_cmp(&self, other: &GenericString<C, L>) -> Option<Ordering> {
+                    self[..].partial_cmp(other.as_str())
+                }
+            }
+        )*
+    };
+}
+
+impl_str_cmp!(str, &str);
+#[cfg(feature = "std")]
+impl_str_cmp!(std::string::String);
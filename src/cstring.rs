@@ -0,0 +1,282 @@
+use crate::{
+    error::{CStringError, FullError, IntoCStringError},
+    traits::{Container, DefaultContainer, Length},
+    GenericString, GenericVec,
+};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    ffi::{c_char, CStr},
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str::{from_utf8, Utf8Error},
+};
+
+fn check_nul(bytes: &[u8]) -> Result<(), CStringError> {
+    match bytes.iter().position(|b| *b == 0) {
+        Some(index) => Err(CStringError::InteriorNul(index)),
+        None => Ok(()),
+    }
+}
+
+/// Nul-terminated string that could be passed to C code without copying.
+///
+/// The container always contains a trailing NUL, so the string could hold up to `capacity - 1` bytes.
+/// Bytes are not required to be valid UTF-8.
+#[repr(transparent)]
+pub struct GenericCString<C: Container<Item = u8> + ?Sized, L: Length = usize> {
+    /// Bytes including the NUL terminator.
+    bytes: GenericVec<C, L>,
+}
+
+/// C string with static capacity of `N` bytes including NUL terminator.
+pub type StaticCString<const N: usize> = GenericCString<[u8; N]>;
+
+impl<C: DefaultContainer<Item = u8>, L: Length> GenericCString<C, L> {
+    /// Create a new empty string.
+    ///
+    /// *Panics if container capacity is zero.*
+    pub fn new() -> Self {
+        let mut bytes = GenericVec::new();
+        assert!(bytes.push(0).is_ok(), "no space for NUL terminator");
+        Self { bytes }
+    }
+
+    /// Creates a string from bytes without NUL terminator.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, CStringError> {
+        let mut self_ = Self::new();
+        self_.push_bytes(bytes)?;
+        Ok(self_)
+    }
+}
+
+impl<C: DefaultContainer<Item = u8>, L: Length> Default for GenericCString<C, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<C: DefaultContainer<Item = u8>, L: Length> Clone for GenericCString<C, L> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+impl<C: DefaultContainer<Item = u8>, L: Length> TryFrom<&str> for GenericCString<C, L> {
+    type Error = CStringError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(s.as_bytes())
+    }
+}
+impl<C: DefaultContainer<Item = u8>, L: Length> TryFrom<&[u8]> for GenericCString<C, L> {
+    type Error = CStringError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(bytes)
+    }
+}
+impl<C: DefaultContainer<Item = u8>, L: Length> TryFrom<&CStr> for GenericCString<C, L> {
+    type Error = FullError;
+
+    fn try_from(s: &CStr) -> Result<Self, Self::Error> {
+        let mut bytes = GenericVec::new();
+        bytes.push_slice(s.to_bytes_with_nul())?;
+        Ok(Self { bytes })
+    }
+}
+
+impl<C: Container<Item = u8>, L: Length> GenericCString<C, L> {
+    /// Converts the string into UTF-8 string with the same container.
+    pub fn into_string(self) -> Result<GenericString<C, L>, Utf8Error> {
+        from_utf8(self.as_bytes())?;
+        let mut bytes = self.bytes;
+        bytes.pop();
+        Ok(unsafe { GenericString::from_utf8_unchecked(bytes) })
+    }
+}
+
+/// Fails if the string contains NUL or there is no space for NUL terminator, the original string is returned with the error.
+impl<C: Container<Item = u8>, L: Length> TryFrom<GenericString<C, L>> for GenericCString<C, L> {
+    type Error = IntoCStringError<GenericString<C, L>>;
+
+    fn try_from(s: GenericString<C, L>) -> Result<Self, Self::Error> {
+        if let Err(e) = check_nul(s.as_bytes()) {
+            return Err(IntoCStringError(e, s));
+        }
+        if s.remaining() == 0 {
+            return Err(IntoCStringError(CStringError::Full, s));
+        }
+        let mut bytes = s.into_bytes();
+        bytes.push(0).unwrap();
+        Ok(Self { bytes })
+    }
+}
+impl<C: Container<Item = u8>, L: Length> TryFrom<GenericCString<C, L>> for GenericString<C, L> {
+    type Error = Utf8Error;
+
+    fn try_from(s: GenericCString<C, L>) -> Result<Self, Self::Error> {
+        s.into_string()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> GenericCString<C, L> {
+    /// Maximum number of bytes excluding NUL terminator.
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity() - 1
+    }
+    /// Number of bytes excluding NUL terminator.
+    pub fn len(&self) -> usize {
+        self.bytes.len() - 1
+    }
+    pub fn remaining(&self) -> usize {
+        self.bytes.remaining()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.bytes.is_full()
+    }
+
+    /// Bytes without NUL terminator.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len()]
+    }
+    /// Bytes with NUL terminator.
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+    pub fn as_c_str(&self) -> &CStr {
+        unsafe { CStr::from_bytes_with_nul_unchecked(self.bytes.as_slice()) }
+    }
+    /// Pointer to nul-terminated string.
+    ///
+    /// The pointer is valid until the string is modified or dropped.
+    pub fn as_ptr(&self) -> *const c_char {
+        self.bytes.as_ptr() as *const c_char
+    }
+    /// String slice if the bytes are valid UTF-8.
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        from_utf8(self.as_bytes())
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+    /// Shortens the string to `new_len` bytes.
+    ///
+    /// If `new_len` is greater than the current length then nothing happens.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            self.bytes.truncate(new_len + 1);
+            self.bytes[new_len] = 0;
+        }
+    }
+
+    pub fn push(&mut self, byte: u8) -> Result<(), CStringError> {
+        self.push_bytes(&[byte])
+    }
+    /// Appends bytes to the string.
+    ///
+    /// If bytes contain NUL or don't fit then nothing is appended.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), CStringError> {
+        check_nul(bytes)?;
+        if bytes.len() > self.remaining() {
+            return Err(CStringError::Full);
+        }
+        unsafe { self.bytes.pop_unchecked() };
+        self.bytes.push_slice(bytes)?;
+        self.bytes.push(0).ok().unwrap();
+        Ok(())
+    }
+    /// Appends string slice to the string.
+    ///
+    /// If the slice contains NUL or doesn't fit then nothing is appended.
+    pub fn push_str(&mut self, s: &str) -> Result<(), CStringError> {
+        self.push_bytes(s.as_bytes())
+    }
+    /// Removes the last byte and returns it.
+    pub fn pop(&mut self) -> Option<u8> {
+        let byte = self.as_bytes().last().copied()?;
+        self.truncate(self.len() - 1);
+        Some(byte)
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Hash for GenericCString<C, L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_c_str().hash(state)
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> fmt::Debug for GenericCString<C, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        fmt::Debug::fmt(self.as_c_str(), f)
+    }
+}
+
+/// Fails if the string contains NUL or doesn't fit, nothing is written in that case.
+impl<C: Container<Item = u8> + ?Sized, L: Length> fmt::Write for GenericCString<C, L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Deref for GenericCString<C, L> {
+    type Target = CStr;
+
+    fn deref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> AsRef<CStr> for GenericCString<C, L> {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Borrow<CStr> for GenericCString<C, L> {
+    fn borrow(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> PartialEq<CStr> for GenericCString<C, L> {
+    fn eq(&self, other: &CStr) -> bool {
+        self.as_c_str() == other
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> PartialEq<&CStr> for GenericCString<C, L> {
+    fn eq(&self, other: &&CStr) -> bool {
+        self.as_c_str() == *other
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length, D: Container<Item = u8> + ?Sized, M: Length>
+    PartialEq<GenericCString<D, M>> for GenericCString<C, L>
+{
+    fn eq(&self, other: &GenericCString<D, M>) -> bool {
+        self.as_bytes().eq(other.as_bytes())
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Eq for GenericCString<C, L> {}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length, D: Container<Item = u8> + ?Sized, M: Length>
+    PartialOrd<GenericCString<D, M>> for GenericCString<C, L>
+{
+    fn partial_cmp(&self, other: &GenericCString<D, M>) -> Option<Ordering> {
+        self.as_bytes().partial_cmp(other.as_bytes())
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> Ord for GenericCString<C, L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
@@ -18,6 +18,27 @@ impl<C: DefaultContainer, L: Length> GenericVec<C, L> {
         self_.extend_until_full(iter);
         self_
     }
+
+    /// Creates a vector from all items of iterator.
+    ///
+    /// If not all items fit into the vector then error is returned.
+    pub fn try_from_iter<I: IntoIterator<Item = C::Item>>(iter: I) -> Result<Self, FullError> {
+        let mut self_ = Self::new();
+        self_.try_extend_exact(iter)?;
+        Ok(self_)
+    }
+}
+
+/// Collects all items from iterator into the vector.
+///
+/// *Panics if the vector capacity is exceeded.* Use [`GenericVec::from_iter_until_full`] to truncate excess items
+/// or [`GenericVec::try_from_iter`] to handle overflow.
+impl<C: DefaultContainer, L: Length> FromIterator<C::Item> for GenericVec<C, L> {
+    fn from_iter<I: IntoIterator<Item = C::Item>>(iter: I) -> Self {
+        let mut self_ = Self::new();
+        self_.extend(iter);
+        self_
+    }
 }
 
 impl<C: DefaultContainer, L: Length> Default for GenericVec<C, L> {
@@ -3,3 +3,91 @@ pub struct FullError;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct EmptyError;
+
+/// Error of vector conversion from bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum BytesError {
+    /// Size of bytes doesn't match the size of the vector.
+    Size,
+    /// Bytes are not properly aligned for the vector.
+    Alignment,
+    /// Stored length is greater than the vector capacity.
+    Length,
+}
+
+/// Error of ASCII string modification.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AsciiError {
+    /// Byte at the position in the input is not ASCII.
+    NotAscii(usize),
+    /// String capacity exceeded.
+    Full,
+}
+
+impl From<FullError> for AsciiError {
+    fn from(FullError: FullError) -> Self {
+        AsciiError::Full
+    }
+}
+
+/// Error of C string modification.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CStringError {
+    /// Byte at the position in the input is NUL.
+    InteriorNul(usize),
+    /// String capacity exceeded.
+    Full,
+}
+
+impl From<FullError> for CStringError {
+    fn from(FullError: FullError) -> Self {
+        CStringError::Full
+    }
+}
+
+/// Error of UTF-16 string modification.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum WideStringError {
+    /// Code unit at the position in the input is an unpaired surrogate.
+    UnpairedSurrogate(usize),
+    /// String capacity exceeded.
+    Full,
+}
+
+impl From<FullError> for WideStringError {
+    fn from(FullError: FullError) -> Self {
+        WideStringError::Full
+    }
+}
+
+/// Error of vector conversion into array, contains the original vector.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct IntoArrayError<V>(pub V);
+
+impl<V> IntoArrayError<V> {
+    /// Returns the original vector.
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+/// Error of string conversion into C string, contains the original string.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct IntoCStringError<S>(pub CStringError, pub S);
+
+impl<S> IntoCStringError<S> {
+    /// Returns the cause of the error.
+    pub fn error(&self) -> CStringError {
+        self.0
+    }
+    /// Returns the original string.
+    pub fn into_inner(self) -> S {
+        self.1
+    }
+}
+
+impl<S> From<IntoCStringError<S>> for CStringError {
+    fn from(e: IntoCStringError<S>) -> Self {
+        e.0
+    }
+}
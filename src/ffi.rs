@@ -0,0 +1,148 @@
+use crate::{traits::Container, GenericVec};
+use core::{ffi::c_void, ptr};
+
+/// Raw view of a vector for C code.
+///
+/// Could be obtained by [`GenericVec::as_raw`].
+///
+/// Because of `repr(C)` layout the vector `GenericVec<[MaybeUninit<T>; N]>` is the same as C structure
+/// `struct { size_t len; T data[N]; }`, so buffers could also be declared in C code (e.g. by `STAVEC_DEFINE` macro from `include/stavec.h`)
+/// and then accessed from Rust by casting the pointer.
+///
+/// Only vectors of `Copy` items could be shared because C code doesn't call destructors.
+#[repr(C)]
+#[derive(Debug)]
+pub struct StavecRaw {
+    /// Pointer to the vector length.
+    pub len: *mut usize,
+    /// Pointer to the first item.
+    pub data: *mut c_void,
+    /// Capacity of the vector.
+    pub capacity: usize,
+    /// Size of a single item in bytes.
+    pub item_size: usize,
+}
+
+impl<C: Container> GenericVec<C, usize>
+where
+    C::Item: Copy,
+{
+    /// Creates a raw view of the vector to pass it into C code.
+    ///
+    /// The view must not outlive the vector.
+    pub fn as_raw(&mut self) -> StavecRaw {
+        StavecRaw {
+            len: &mut self.len,
+            data: self.data.as_mut().as_mut_ptr() as *mut c_void,
+            capacity: self.capacity(),
+            item_size: core::mem::size_of::<C::Item>(),
+        }
+    }
+}
+
+/// Number of items in the vector.
+///
+/// # Safety
+///
+/// `vec` must point to a valid view.
+#[no_mangle]
+pub unsafe extern "C" fn stavec_len(vec: *const StavecRaw) -> usize {
+    *(*vec).len
+}
+
+/// Capacity of the vector.
+///
+/// # Safety
+///
+/// `vec` must point to a valid view.
+#[no_mangle]
+pub unsafe extern "C" fn stavec_capacity(vec: *const StavecRaw) -> usize {
+    (*vec).capacity
+}
+
+/// Pointer to the first item of the vector.
+///
+/// # Safety
+///
+/// `vec` must point to a valid view.
+#[no_mangle]
+pub unsafe extern "C" fn stavec_data(vec: *const StavecRaw) -> *mut c_void {
+    (*vec).data
+}
+
+/// Copies an item to the end of the vector.
+///
+/// Returns `false` if the vector is full.
+///
+/// # Safety
+///
+/// `vec` must point to a valid view, `item` must point to `item_size` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn stavec_push(vec: *mut StavecRaw, item: *const c_void) -> bool {
+    let vec = &mut *vec;
+    let len = *vec.len;
+    if len >= vec.capacity {
+        return false;
+    }
+    ptr::copy_nonoverlapping(
+        item as *const u8,
+        (vec.data as *mut u8).add(len * vec.item_size),
+        vec.item_size,
+    );
+    *vec.len = len + 1;
+    true
+}
+
+/// Removes the last item of the vector and copies it to `item` if it isn't null.
+///
+/// Returns `false` if the vector is empty.
+///
+/// # Safety
+///
+/// `vec` must point to a valid view, `item` must be null or point to `item_size` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn stavec_pop(vec: *mut StavecRaw, item: *mut c_void) -> bool {
+    let vec = &mut *vec;
+    let len = *vec.len;
+    if len == 0 {
+        return false;
+    }
+    if !item.is_null() {
+        ptr::copy_nonoverlapping(
+            (vec.data as *const u8).add((len - 1) * vec.item_size),
+            item as *mut u8,
+            vec.item_size,
+        );
+    }
+    *vec.len = len - 1;
+    true
+}
+
+/// Appends a byte to the end of the byte vector.
+///
+/// Returns `false` if the vector is full or its items are not bytes.
+///
+/// # Safety
+///
+/// `vec` must point to a valid view.
+#[no_mangle]
+pub unsafe extern "C" fn stavec_push_byte(vec: *mut StavecRaw, byte: u8) -> bool {
+    (*vec).item_size == 1 && stavec_push(vec, &byte as *const u8 as *const c_void)
+}
+
+/// Removes and returns the last byte of the byte vector.
+///
+/// Returns `-1` if the vector is empty or its items are not bytes.
+///
+/// # Safety
+///
+/// `vec` must point to a valid view.
+#[no_mangle]
+pub unsafe extern "C" fn stavec_pop_byte(vec: *mut StavecRaw) -> i32 {
+    let mut byte = 0u8;
+    if (*vec).item_size == 1 && stavec_pop(vec, &mut byte as *mut u8 as *mut c_void) {
+        byte as i32
+    } else {
+        -1
+    }
+}
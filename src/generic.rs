@@ -1,7 +1,7 @@
 use crate::{
     error::FullError,
     traits::{Container, Length, Slot},
-    utils::{slice_assume_init_mut, slice_assume_init_ref, uninit_write_slice_cloned},
+    utils::{slice_assume_init_mut, slice_assume_init_ref},
 };
 use core::{
     borrow::{Borrow, BorrowMut},
@@ -9,11 +9,10 @@ use core::{
     fmt,
     hash::{Hash, Hasher},
     iter::IntoIterator,
-    mem,
+    mem::{self, MaybeUninit},
     ops::{Deref, DerefMut, Index, IndexMut},
     ptr,
-    slice::SliceIndex,
-    slice::{Iter, IterMut},
+    slice::{self, Iter, IterMut, SliceIndex},
 };
 use num_traits::clamp_max;
 
@@ -120,6 +119,20 @@ impl<C: Container + ?Sized, L: Length> GenericVec<C, L> {
         }
     }
 
+    /// Inserts an item at position `index` within the vector, shifting all items after it to the right.
+    ///
+    /// If the vector is already full then the item is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: C::Item) -> Result<(), C::Item> {
+        assert!(index <= self.len());
+        self.push(value)?;
+        self.as_mut_slice()[index..].rotate_right(1);
+        Ok(())
+    }
+
     /// Truncates the vector. Excess items are simply dropped.
     ///
     /// If `new_len` is greater then vector length the methods simply does nothing.
@@ -218,6 +231,17 @@ impl<C: Container + ?Sized, L: Length> GenericVec<C, L> {
         unsafe { self.data.as_mut().get_unchecked_mut(len..cap) }
     }
 
+    /// Sets the length of the vector.
+    ///
+    /// # Safety
+    ///
+    /// `new_len` must not exceed [`capacity()`](`Self::capacity`) and items with indices lower than `new_len` must be initialized.
+    /// Items between `new_len` and the old length are not dropped.
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+        self.len = L::from_usize(new_len).unwrap();
+    }
+
     /// Appends items from iterator to the vector until iterator ends or the vector is full.
     pub fn extend_until_full<I: IntoIterator<Item = C::Item>>(&mut self, iter: I) {
         for x in iter.into_iter().take(self.capacity() - self.len()) {
@@ -225,13 +249,89 @@ impl<C: Container + ?Sized, L: Length> GenericVec<C, L> {
         }
     }
 
+    /// Appends items from iterator to the vector until iterator ends.
+    ///
+    /// If the vector becomes full then the first item that doesn't fit is returned along with the rest of iterator.
+    pub fn try_extend<I: IntoIterator<Item = C::Item>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), (C::Item, I::IntoIter)> {
+        let mut iter = iter.into_iter();
+        while let Some(x) = iter.next() {
+            if let Err(x) = self.push(x) {
+                return Err((x, iter));
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends all items from iterator to the vector.
+    ///
+    /// If not all items fit then the vector is truncated back to its original length and error is returned.
+    pub fn try_extend_exact<I: IntoIterator<Item = C::Item>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), FullError> {
+        let len = self.len();
+        match self.try_extend(iter) {
+            Ok(()) => Ok(()),
+            Err(_) => {
+                self.truncate(len);
+                Err(FullError)
+            }
+        }
+    }
+
+    /// Moves items of the array to the end of the vector.
+    ///
+    /// If the array doesn't fit then it is returned back.
+    pub fn push_array<const K: usize>(&mut self, array: [C::Item; K]) -> Result<(), [C::Item; K]> {
+        if K > self.remaining() {
+            return Err(array);
+        }
+        let array = mem::ManuallyDrop::new(array);
+        unsafe {
+            ptr::copy_nonoverlapping(
+                array.as_ptr(),
+                self.free_space_as_mut_slice().as_mut_ptr() as *mut C::Item,
+                K,
+            )
+        };
+        self.len += L::from_usize(K).unwrap();
+        Ok(())
+    }
+
+    /// Splits items into chunks of `K` items and the remainder.
+    ///
+    /// *Panics if `K` is zero.*
+    pub fn as_chunks<const K: usize>(&self) -> (&[[C::Item; K]], &[C::Item]) {
+        assert!(K != 0, "chunk size must be non-zero");
+        let (chunks, rem) = self.split_at(self.len() / K * K);
+        let chunks = unsafe {
+            slice::from_raw_parts(chunks.as_ptr() as *const [C::Item; K], chunks.len() / K)
+        };
+        (chunks, rem)
+    }
+
+    /// Splits items into mutable chunks of `K` items and the remainder.
+    ///
+    /// *Panics if `K` is zero.*
+    pub fn as_chunks_mut<const K: usize>(&mut self) -> (&mut [[C::Item; K]], &mut [C::Item]) {
+        assert!(K != 0, "chunk size must be non-zero");
+        let len = self.len() / K * K;
+        let (chunks, rem) = self.split_at_mut(len);
+        let chunks =
+            unsafe { slice::from_raw_parts_mut(chunks.as_mut_ptr() as *mut [C::Item; K], len / K) };
+        (chunks, rem)
+    }
+
     /// Returns iterator over references of vector items.
-    pub fn iter(&self) -> Iter<C::Item> {
+    pub fn iter(&self) -> Iter<'_, C::Item> {
         self.as_slice().iter()
     }
 
     /// Returns iterator over mutable references of vector items.
-    pub fn iter_mut(&mut self) -> IterMut<C::Item> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, C::Item> {
         self.as_mut_slice().iter_mut()
     }
 }
@@ -244,18 +344,13 @@ where
     ///
     /// Returns `Err` if slice length is greater than the number of remaining slots in the vector and does not copy items.
     pub fn push_slice(&mut self, slice: &[C::Item]) -> Result<(), FullError> {
-        let free_space = self.free_space_as_mut_slice();
-        if slice.len() > free_space.len() {
+        if slice.len() > self.remaining() {
             Err(FullError)
         } else {
-            let len = slice.len();
-            unsafe {
-                uninit_write_slice_cloned(
-                    free_space.get_unchecked_mut(..len),
-                    slice.get_unchecked(..len),
-                );
+            // Items are committed one by one, so already cloned items are dropped with the vector if `clone` panics.
+            for x in slice {
+                unsafe { self.push_unchecked(x.clone()) };
             }
-            self.len += L::from_usize(len).unwrap();
             Ok(())
         }
     }
@@ -279,11 +374,87 @@ where
     }
 }
 
+/// Appends all items from iterator to the vector.
+///
+/// *Panics if the vector capacity is exceeded.* Use [`GenericVec::extend_until_full`] to truncate excess items
+/// or [`GenericVec::try_extend`] to handle overflow.
+impl<C: Container + ?Sized, L: Length> Extend<C::Item> for GenericVec<C, L> {
+    fn extend<I: IntoIterator<Item = C::Item>>(&mut self, iter: I) {
+        if self.try_extend(iter).is_err() {
+            panic!("vector capacity exceeded");
+        }
+    }
+}
+
+impl<T, C: Container<Item = T, Slot = MaybeUninit<T>> + ?Sized, L: Length> GenericVec<C, L> {
+    /// Mutable slice of remaining free space in vector.
+    ///
+    /// Written items become a part of the vector after [`set_len()`](`Self::set_len`).
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self.free_space_as_mut_slice()
+    }
+}
+
+impl<T: Copy, C: Container<Item = T, Slot = T> + ?Sized, L: Length> GenericVec<C, L> {
+    /// Passes remaining free space to `f` and appends the number of first items returned from it.
+    ///
+    /// Slots of such containers are always initialized (e.g. zeroed), so `f` could write them safely.
+    /// For containers of [`MaybeUninit`] use [`spare_capacity_mut()`](`Self::spare_capacity_mut`) and [`set_len()`](`Self::set_len`).
+    ///
+    /// Returns the number of appended items.
+    ///
+    /// *Panics if `f` returns number greater than the free space length.*
+    pub fn fill_spare<F: FnOnce(&mut [T]) -> usize>(&mut self, f: F) -> usize {
+        let spare = self.free_space_as_mut_slice();
+        let max = spare.len();
+        let count = f(spare);
+        assert!(count <= max, "filled more items than free space");
+        unsafe { self.set_len(self.len() + count) };
+        count
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Container<Item = u8> + ?Sized, L: Length> GenericVec<C, L> {
+    /// Reads bytes from `reader` directly into the free space of the vector.
+    ///
+    /// Performs a single [`read()`](`std::io::Read::read`) call and returns the number of appended bytes.
+    ///
+    /// *Panics if `reader` reports more bytes than the free space length.*
+    pub fn read_into<R: std::io::Read + ?Sized>(
+        &mut self,
+        reader: &mut R,
+    ) -> std::io::Result<usize> {
+        let spare = self.free_space_as_mut_slice();
+        for slot in spare.iter_mut() {
+            *slot = Slot::new(0);
+        }
+        let buf = unsafe { slice_assume_init_mut(spare) };
+        let max = buf.len();
+        let count = reader.read(buf)?;
+        assert!(count <= max, "read more bytes than free space");
+        unsafe { self.set_len(self.len() + count) };
+        Ok(count)
+    }
+}
+
+/// Writes as many bytes as the vector can fit.
+#[cfg(feature = "std")]
+impl<C: Container<Item = u8> + ?Sized, L: Length> std::io::Write for GenericVec<C, L> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(self.remaining());
+        self.push_slice(&buf[..len]).unwrap();
+        Ok(len)
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 impl<C: Container + ?Sized, L: Length> Drop for GenericVec<C, L> {
     fn drop(&mut self) {
-        for i in 0..self.len() {
-            mem::drop(unsafe { self.data.as_mut().get_unchecked_mut(i).assume_init_read() });
-        }
+        // Remaining items are still dropped if one of them panics.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
     }
 }
 
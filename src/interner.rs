@@ -0,0 +1,115 @@
+use crate::{error::FullError, GenericString, GenericVec};
+use core::{fmt, ops::Range};
+
+/// Identifier of a string interned in [`StaticInterner`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Index of the symbol in order of interning.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Symbol table with static capacity of `BYTES` bytes for all strings and up to `SYMBOLS` symbols.
+///
+/// Strings are stored one after another in a single string, symbols refer to their ends in a separate table.
+/// Lookup is a linear search, so the interner is intended for small sets of short strings.
+pub struct StaticInterner<const BYTES: usize, const SYMBOLS: usize> {
+    strings: GenericString<[u8; BYTES]>,
+    ends: GenericVec<[usize; SYMBOLS]>,
+}
+
+impl<const BYTES: usize, const SYMBOLS: usize> StaticInterner<BYTES, SYMBOLS> {
+    /// Create a new empty interner.
+    pub fn new() -> Self {
+        Self {
+            strings: GenericString::default(),
+            ends: GenericVec::new(),
+        }
+    }
+
+    /// Number of interned strings.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Checks whether there are no interned strings.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Maximum number of symbols.
+    pub fn capacity(&self) -> usize {
+        SYMBOLS
+    }
+
+    /// Maximum total length of all strings in bytes.
+    pub fn byte_capacity(&self) -> usize {
+        BYTES
+    }
+
+    fn range(&self, index: usize) -> Range<usize> {
+        let start = match index {
+            0 => 0,
+            i => self.ends[i - 1],
+        };
+        start..self.ends[index]
+    }
+
+    /// Returns symbol of the string if it is already interned.
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        (0..self.len())
+            .find(|&i| &self.strings[self.range(i)] == s)
+            .map(|i| Symbol(i as u32))
+    }
+
+    /// Returns symbol of the string interning it if needed.
+    ///
+    /// If the string is not interned yet and there is no space for it then error is returned.
+    pub fn intern(&mut self, s: &str) -> Result<Symbol, FullError> {
+        if let Some(symbol) = self.get(s) {
+            return Ok(symbol);
+        }
+        let index = u32::try_from(self.len()).map_err(|_| FullError)?;
+        if self.ends.is_full() {
+            return Err(FullError);
+        }
+        self.strings.push_str(s)?;
+        self.ends.push(self.strings.len()).unwrap();
+        Ok(Symbol(index))
+    }
+
+    /// Returns the string of the symbol or `None` if the symbol doesn't belong to the interner.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        if symbol.index() < self.len() {
+            Some(&self.strings[self.range(symbol.index())])
+        } else {
+            None
+        }
+    }
+
+    /// Returns iterator over symbols and their strings in order of interning.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> + '_ {
+        (0..self.len()).map(move |i| (Symbol(i as u32), &self.strings[self.range(i)]))
+    }
+
+    /// Removes all symbols.
+    pub fn clear(&mut self) {
+        self.strings.clear();
+        self.ends.clear();
+    }
+}
+
+impl<const BYTES: usize, const SYMBOLS: usize> Default for StaticInterner<BYTES, SYMBOLS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BYTES: usize, const SYMBOLS: usize> fmt::Debug for StaticInterner<BYTES, SYMBOLS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_list().entries(self.iter().map(|(_, s)| s)).finish()
+    }
+}
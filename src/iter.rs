@@ -1,5 +1,8 @@
-use crate::traits::{Container, Length, Slot};
-use core::{iter::ExactSizeIterator, mem, ops::Range};
+use crate::{
+    traits::{Container, Length, Slot},
+    utils::slice_assume_init_mut,
+};
+use core::{iter::ExactSizeIterator, ops::Range, ptr};
 
 /// Iterator by values of static vector.
 pub struct IntoIter<C: Container + ?Sized, L: Length> {
@@ -12,7 +15,9 @@ impl<C: Container, L: Length> IntoIter<C, L> {
         debug_assert!(range.end <= L::from_usize(data.as_ref().len()).unwrap());
         Self { data, range }
     }
+}
 
+impl<C: Container + ?Sized, L: Length> IntoIter<C, L> {
     pub fn len(&self) -> usize {
         (self.range.end - self.range.start).to_usize().unwrap()
     }
@@ -49,10 +54,12 @@ impl<C: Container + ?Sized, L: Length> ExactSizeIterator for IntoIter<C, L> {}
 impl<C: Container + ?Sized, L: Length> Drop for IntoIter<C, L> {
     fn drop(&mut self) {
         let range = self.range.start.to_usize().unwrap()..self.range.end.to_usize().unwrap();
+        self.range.start = self.range.end;
+        // Remaining items are still dropped if one of them panics.
         unsafe {
-            for x in self.data.as_mut().get_unchecked_mut(range) {
-                mem::drop(x.assume_init_read());
-            }
-        }
+            ptr::drop_in_place(slice_assume_init_mut(
+                self.data.as_mut().get_unchecked_mut(range),
+            ))
+        };
     }
 }
@@ -0,0 +1,205 @@
+use crate::{
+    error::FullError,
+    traits::{Container, DefaultContainer, Length},
+    GenericVec, IntoIter,
+};
+use core::{
+    fmt,
+    mem::{self, MaybeUninit},
+    ops::Range,
+};
+
+/// Vector of variable-length rows packed into a single container.
+///
+/// The type parametrized by:
+/// + `C` - container of all items.
+/// + `R` - container of row ends, its capacity is the maximum number of rows.
+/// + `L` - type of the length and row ends.
+pub struct GenericJagged<C: Container, R: Container<Item = L>, L: Length = usize> {
+    items: GenericVec<C, L>,
+    ends: GenericVec<R, L>,
+}
+
+/// Jagged vector with static capacity of `N` items in total and up to `M` rows.
+pub type StaticJagged<T, const N: usize, const M: usize> =
+    GenericJagged<[MaybeUninit<T>; N], [usize; M]>;
+
+impl<C: DefaultContainer, R: DefaultContainer<Item = L>, L: Length> GenericJagged<C, R, L> {
+    /// Create a new vector without rows.
+    pub fn new() -> Self {
+        Self {
+            items: GenericVec::new(),
+            ends: GenericVec::new(),
+        }
+    }
+}
+
+impl<C: DefaultContainer, R: DefaultContainer<Item = L>, L: Length> Default
+    for GenericJagged<C, R, L>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Container, R: Container<Item = L>, L: Length> GenericJagged<C, R, L> {
+    /// Number of rows.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Checks whether there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Maximum number of rows.
+    pub fn row_capacity(&self) -> usize {
+        self.ends.capacity()
+    }
+
+    /// Maximum total number of items in all rows.
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+
+    /// Total number of items in all rows.
+    pub fn total_len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Number of remaining free places for items.
+    pub fn remaining(&self) -> usize {
+        self.items.remaining()
+    }
+
+    /// Items of all rows concatenated.
+    pub fn as_flat_slice(&self) -> &[C::Item] {
+        self.items.as_slice()
+    }
+
+    fn row_range(&self, index: usize) -> Range<usize> {
+        let start = match index {
+            0 => 0,
+            i => self.ends[i - 1].to_usize().unwrap(),
+        };
+        start..self.ends[index].to_usize().unwrap()
+    }
+
+    /// Returns row at `index` or `None` if out of bounds.
+    pub fn get_row(&self, index: usize) -> Option<&[C::Item]> {
+        if index < self.len() {
+            Some(&self.items[self.row_range(index)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns mutable row at `index` or `None` if out of bounds.
+    pub fn get_row_mut(&mut self, index: usize) -> Option<&mut [C::Item]> {
+        if index < self.len() {
+            let range = self.row_range(index);
+            Some(&mut self.items[range])
+        } else {
+            None
+        }
+    }
+
+    /// Returns row at `index`.
+    ///
+    /// *Panics if `index` is out of bounds.*
+    pub fn row(&self, index: usize) -> &[C::Item] {
+        self.get_row(index).expect("row index out of bounds")
+    }
+
+    /// Returns mutable row at `index`.
+    ///
+    /// *Panics if `index` is out of bounds.*
+    pub fn row_mut(&mut self, index: usize) -> &mut [C::Item] {
+        self.get_row_mut(index).expect("row index out of bounds")
+    }
+
+    /// Returns iterator over rows.
+    pub fn rows(&self) -> impl Iterator<Item = &[C::Item]> + '_ {
+        (0..self.len()).map(move |i| self.row(i))
+    }
+
+    /// Appends a new row with items from iterator.
+    ///
+    /// If the row doesn't fit then nothing is appended and error is returned.
+    pub fn push_row_from_iter<I: IntoIterator<Item = C::Item>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), FullError> {
+        self.push_row_with(|items| items.try_extend_exact(iter))
+    }
+
+    /// Appends a new row with items pushed by `f`.
+    ///
+    /// Items pushed before `f` fails or panics are removed.
+    fn push_row_with<F: FnOnce(&mut GenericVec<C, L>) -> Result<(), FullError>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), FullError> {
+        if self.ends.is_full() {
+            return Err(FullError);
+        }
+        let guard = PushRowGuard(self);
+        f(&mut guard.0.items)?;
+        mem::forget(guard);
+        unsafe { self.ends.push_unchecked(self.items.len) };
+        Ok(())
+    }
+
+    /// Removes the last row and returns iterator over its items.
+    ///
+    /// Items which are not taken from iterator are dropped with it.
+    pub fn pop_row(&mut self) -> Option<IntoIter<&mut [C::Slot], L>> {
+        let end = self.ends.pop()?;
+        let start = self.ends.as_slice().last().copied().unwrap_or(L::zero());
+        self.items.len = start;
+        Some(IntoIter::new(
+            unsafe { self.items.data_mut() }.as_mut(),
+            start..end,
+        ))
+    }
+
+    /// Removes all rows.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.ends.clear();
+    }
+}
+
+impl<C: Container, R: Container<Item = L>, L: Length> GenericJagged<C, R, L>
+where
+    C::Item: Clone,
+{
+    /// Appends a new row with cloned items from slice.
+    ///
+    /// If the row doesn't fit then nothing is appended and error is returned.
+    pub fn push_row(&mut self, row: &[C::Item]) -> Result<(), FullError> {
+        self.push_row_with(|items| items.push_slice(row))
+    }
+}
+
+/// Removes items of unfinished row.
+struct PushRowGuard<'a, C: Container, R: Container<Item = L>, L: Length>(
+    &'a mut GenericJagged<C, R, L>,
+);
+
+impl<C: Container, R: Container<Item = L>, L: Length> Drop for PushRowGuard<'_, C, R, L> {
+    fn drop(&mut self) {
+        let start = self.0.ends.as_slice().last().copied().unwrap_or(L::zero());
+        self.0.items.truncate(start.to_usize().unwrap());
+    }
+}
+
+impl<C: Container, R: Container<Item = L>, L: Length> fmt::Debug for GenericJagged<C, R, L>
+where
+    C::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
@@ -1,27 +1,67 @@
 #![no_std]
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", loom))]
 extern crate std;
 
 pub mod error;
+/// C interface for vectors.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+/// Wrappers that handle vector overflow according to chosen policy.
+pub mod policy;
 /// Traits for [`GenericVec`] parameters.
 ///
 /// You probably won't need them is you use only [`StaticVec`].
 pub mod traits;
 
+mod arena;
+mod ascii;
+#[cfg(target_has_atomic = "ptr")]
+mod atomic;
+mod bits;
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+mod bytes;
 mod cmp;
+mod cstring;
 mod default;
 mod generic;
+mod interner;
 mod iter;
+mod jagged;
+#[cfg(feature = "mmap")]
+mod mmap;
 mod sized;
+mod slab;
+mod slice;
+mod spsc;
 mod static_;
 mod string;
+mod sync;
+#[cfg(feature = "unicode")]
+mod unicode;
+mod utf8;
 mod utils;
+mod wide;
 
 #[cfg(test)]
 mod tests;
 
+pub use arena::StaticArena;
+pub use ascii::{GenericAsciiString, StaticAsciiString};
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::AtomicStaticVec;
+pub use bits::{GenericBitVec, StaticBitVec};
+pub use cstring::{GenericCString, StaticCString};
 pub use generic::GenericVec;
+pub use interner::{StaticInterner, Symbol};
 pub use iter::IntoIter;
+pub use jagged::{GenericJagged, StaticJagged};
+#[cfg(feature = "mmap")]
+pub use mmap::{MmapContainer, MmapVec};
+pub use slab::{GenericSlab, SlabEntry, SlabKey, StaticSlab};
+pub use slice::SliceVec;
+pub use spsc::{Consumer, GenericSpsc, Producer, StaticSpsc};
 pub use static_::StaticVec;
 pub use string::GenericString;
+pub use utf8::Utf8Decoder;
+pub use wide::{GenericWideString, StaticWideString};
@@ -0,0 +1,186 @@
+use crate::{traits::Container, GenericVec};
+use bytemuck::Pod;
+use core::{
+    convert::{AsMut, AsRef},
+    marker::PhantomData,
+    mem::{align_of, size_of, MaybeUninit},
+    slice,
+};
+use memmap2::MmapMut;
+use std::{
+    fs::{File, OpenOptions},
+    io,
+    path::Path,
+};
+
+const MAGIC: [u8; 8] = *b"STAVEC\0\0";
+const MAGIC_OFFSET: usize = 0;
+const ITEM_SIZE_OFFSET: usize = 8;
+const CAPACITY_OFFSET: usize = 16;
+const LEN_OFFSET: usize = 24;
+const RAW_HEADER_SIZE: usize = 32;
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Container over memory-mapped file.
+///
+/// The file consists of a header followed by items.
+/// The header contains magic bytes, item size, capacity and length of the vector, all numbers are stored in native byte order.
+pub struct MmapContainer<T: Pod> {
+    map: MmapMut,
+    capacity: usize,
+    _ghost: PhantomData<T>,
+}
+
+impl<T: Pod> MmapContainer<T> {
+    /// Size of the header padded to the item alignment.
+    const HEADER_SIZE: usize = RAW_HEADER_SIZE.next_multiple_of(align_of::<T>());
+
+    fn file_size(capacity: usize) -> Option<usize> {
+        capacity
+            .checked_mul(size_of::<T>())?
+            .checked_add(Self::HEADER_SIZE)
+    }
+
+    fn read_u64(&self, offset: usize) -> u64 {
+        u64::from_ne_bytes(self.map[offset..(offset + 8)].try_into().unwrap())
+    }
+    fn write_u64(&mut self, offset: usize, value: u64) {
+        self.map[offset..(offset + 8)].copy_from_slice(&value.to_ne_bytes());
+    }
+
+    /// # Safety
+    ///
+    /// The file must not be modified by other means while it is mapped.
+    unsafe fn map(file: &File) -> io::Result<Self> {
+        if size_of::<T>() == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "zero-sized items are not supported",
+            ));
+        }
+        let map = MmapMut::map_mut(file)?;
+        if map.len() < Self::HEADER_SIZE || map[MAGIC_OFFSET..(MAGIC_OFFSET + 8)] != MAGIC {
+            return Err(invalid_data("bad header"));
+        }
+        let mut self_ = Self {
+            map,
+            capacity: 0,
+            _ghost: PhantomData,
+        };
+        if self_.read_u64(ITEM_SIZE_OFFSET) != size_of::<T>() as u64 {
+            return Err(invalid_data("item size mismatch"));
+        }
+        let capacity = usize::try_from(self_.read_u64(CAPACITY_OFFSET))
+            .map_err(|_| invalid_data("capacity is too large"))?;
+        if Self::file_size(capacity) != Some(self_.map.len()) {
+            return Err(invalid_data("file size mismatch"));
+        }
+        self_.capacity = capacity;
+        Ok(self_)
+    }
+
+    fn load_len(&self) -> io::Result<usize> {
+        match usize::try_from(self.read_u64(LEN_OFFSET)) {
+            Ok(len) if len <= self.capacity => Ok(len),
+            _ => Err(invalid_data("length is greater than capacity")),
+        }
+    }
+    fn store_len(&mut self, len: usize) {
+        self.write_u64(LEN_OFFSET, len as u64);
+    }
+}
+
+impl<T: Pod> AsRef<[MaybeUninit<T>]> for MmapContainer<T> {
+    fn as_ref(&self) -> &[MaybeUninit<T>] {
+        unsafe {
+            slice::from_raw_parts(
+                self.map.as_ptr().add(Self::HEADER_SIZE) as *const MaybeUninit<T>,
+                self.capacity,
+            )
+        }
+    }
+}
+
+impl<T: Pod> AsMut<[MaybeUninit<T>]> for MmapContainer<T> {
+    fn as_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.map.as_mut_ptr().add(Self::HEADER_SIZE) as *mut MaybeUninit<T>,
+                self.capacity,
+            )
+        }
+    }
+}
+
+unsafe impl<T: Pod> Container for MmapContainer<T> {
+    type Item = T;
+    type Slot = MaybeUninit<T>;
+}
+
+/// Vector stored in memory-mapped file.
+///
+/// Length of the vector is persisted in the file only on [`flush()`](`Self::flush`).
+/// Dropping the vector doesn't store the length, so items pushed after the last flush are lost on the next [`open()`](`Self::open`).
+pub type MmapVec<T> = GenericVec<MmapContainer<T>>;
+
+impl<T: Pod> MmapVec<T> {
+    /// Creates a new file with specified capacity and maps an empty vector onto it.
+    ///
+    /// If the file already exists it is truncated.
+    ///
+    /// The vector must be [flushed](`Self::flush`) before drop, otherwise the file is opened as empty.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by other means while it is mapped.
+    pub unsafe fn create<P: AsRef<Path>>(path: P, capacity: usize) -> io::Result<Self> {
+        let size = MmapContainer::<T>::file_size(capacity)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "capacity is too large"))?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len(size as u64)?;
+        {
+            let mut map = MmapMut::map_mut(&file)?;
+            map[MAGIC_OFFSET..(MAGIC_OFFSET + 8)].copy_from_slice(&MAGIC);
+            map[ITEM_SIZE_OFFSET..(ITEM_SIZE_OFFSET + 8)]
+                .copy_from_slice(&(size_of::<T>() as u64).to_ne_bytes());
+            map[CAPACITY_OFFSET..(CAPACITY_OFFSET + 8)]
+                .copy_from_slice(&(capacity as u64).to_ne_bytes());
+        }
+        Self::from_file(&file)
+    }
+
+    /// Opens an existing file and maps the vector onto it.
+    ///
+    /// Returns an error if the file header is invalid or does not match the item type.
+    ///
+    /// The length is loaded as it was stored by the last [`flush()`](`Self::flush`).
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by other means while it is mapped.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        Self::from_file(&file)
+    }
+
+    unsafe fn from_file(file: &File) -> io::Result<Self> {
+        let data = MmapContainer::map(file)?;
+        let len = data.load_len()?;
+        Ok(Self::from_raw_parts(data, len))
+    }
+
+    /// Stores the vector length into the file header and flushes all changes to the file.
+    pub fn flush(&mut self) -> io::Result<()> {
+        let len = self.len();
+        self.data.store_len(len);
+        self.data.map.flush()
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{
+    traits::{Container, Length},
+    GenericString, GenericVec,
+};
+use core::{fmt, marker::PhantomData, ops::Deref, slice::IterMut};
+
+/// Action performed when an item doesn't fit into the full vector.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Overflow {
+    /// The new item is rejected.
+    Reject,
+    /// Panic.
+    Panic,
+    /// The last item is removed to free a place.
+    OverwriteLast,
+    /// The first (oldest) item is removed to free a place.
+    ///
+    /// Remaining items are shifted, so each overflowing insertion costs *O*(*n*).
+    EvictOldest,
+}
+
+/// Overflow policy that could be passed as a type parameter of [`WithPolicy`].
+pub trait OverflowPolicy {
+    const ACTION: Overflow;
+}
+
+/// Reject new items when full.
+pub struct Reject;
+/// Panic when full.
+pub struct Panic;
+/// Replace the last item when full.
+pub struct OverwriteLast;
+/// Remove the first item when full.
+///
+/// Remaining items are shifted, so each overflowing insertion costs *O*(*n*).
+pub struct EvictOldest;
+
+impl OverflowPolicy for Reject {
+    const ACTION: Overflow = Overflow::Reject;
+}
+impl OverflowPolicy for Panic {
+    const ACTION: Overflow = Overflow::Panic;
+}
+impl OverflowPolicy for OverwriteLast {
+    const ACTION: Overflow = Overflow::OverwriteLast;
+}
+impl OverflowPolicy for EvictOldest {
+    const ACTION: Overflow = Overflow::EvictOldest;
+}
+
+/// Vector or string which handles overflow according to the policy `P`.
+///
+/// Insertion methods of the wrapper never fail, instead they return discarded content.
+/// Read-only methods of the inner vector or string are available through [`Deref`],
+/// mutators that couldn't overflow are forwarded explicitly, so the policy couldn't be bypassed.
+///
+/// ```compile_fail
+/// # use stavec::{policy::Panicking, StaticVec};
+/// let mut v = Panicking::new(StaticVec::<i32, 1>::new());
+/// (*v).push(0);
+/// ```
+#[repr(transparent)]
+pub struct WithPolicy<V: ?Sized, P: OverflowPolicy> {
+    _policy: PhantomData<P>,
+    inner: V,
+}
+
+/// Vector or string that rejects items which don't fit.
+pub type Saturating<V> = WithPolicy<V, Reject>;
+/// Vector or string that panics on overflow.
+pub type Panicking<V> = WithPolicy<V, Panic>;
+/// Vector or string that replaces the last item on overflow.
+pub type Overwriting<V> = WithPolicy<V, OverwriteLast>;
+/// Vector or string that removes the oldest items on overflow.
+pub type Evicting<V> = WithPolicy<V, EvictOldest>;
+
+impl<V, P: OverflowPolicy> WithPolicy<V, P> {
+    pub fn new(inner: V) -> Self {
+        Self {
+            _policy: PhantomData,
+            inner,
+        }
+    }
+    pub fn into_inner(self) -> V {
+        self.inner
+    }
+}
+
+impl<V: Default, P: OverflowPolicy> Default for WithPolicy<V, P> {
+    fn default() -> Self {
+        Self::new(V::default())
+    }
+}
+
+impl<V: Clone, P: OverflowPolicy> Clone for WithPolicy<V, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+impl<V: ?Sized, P: OverflowPolicy> Deref for WithPolicy<V, P> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.inner
+    }
+}
+
+impl<V: fmt::Debug + ?Sized, P: OverflowPolicy> fmt::Debug for WithPolicy<V, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<C: Container + ?Sized, L: Length, P: OverflowPolicy> WithPolicy<GenericVec<C, L>, P> {
+    /// Inserts an item at position `index` within the vector, shifting all items after it to the right.
+    ///
+    /// Returns an item discarded according to the policy, it may be the inserted item itself.
+    ///
+    /// *Panics if `index > len`.*
+    pub fn insert(&mut self, index: usize, value: C::Item) -> Option<C::Item> {
+        let vec = &mut self.inner;
+        assert!(index <= vec.len());
+        let (index, discarded) = if !vec.is_full() {
+            (index, None)
+        } else {
+            match P::ACTION {
+                Overflow::Reject => return Some(value),
+                Overflow::Panic => panic!("vector capacity exceeded"),
+                Overflow::OverwriteLast => match vec.pop() {
+                    Some(last) => (index.min(vec.len()), Some(last)),
+                    None => return Some(value),
+                },
+                Overflow::EvictOldest => {
+                    if index == 0 {
+                        return Some(value);
+                    }
+                    (index - 1, Some(vec.remove(0)))
+                }
+            }
+        };
+        if vec.insert(index, value).is_err() {
+            unreachable!();
+        }
+        discarded
+    }
+
+    /// Appends an item to the end of the vector.
+    ///
+    /// Returns an item discarded according to the policy, it may be the pushed item itself.
+    pub fn push(&mut self, value: C::Item) -> Option<C::Item> {
+        self.insert(self.inner.len(), value)
+    }
+
+    pub fn pop(&mut self) -> Option<C::Item> {
+        self.inner.pop()
+    }
+    pub fn remove(&mut self, index: usize) -> C::Item {
+        self.inner.remove(index)
+    }
+    pub fn swap_remove(&mut self, index: usize) -> C::Item {
+        self.inner.swap_remove(index)
+    }
+    pub fn truncate(&mut self, new_len: usize) {
+        self.inner.truncate(new_len);
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+    pub fn as_mut_slice(&mut self) -> &mut [C::Item] {
+        self.inner.as_mut_slice()
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, C::Item> {
+        self.inner.iter_mut()
+    }
+}
+
+impl<C: Container + ?Sized, L: Length, P: OverflowPolicy> WithPolicy<GenericVec<C, L>, P>
+where
+    C::Item: Clone,
+{
+    /// Clones and appends items from the slice.
+    ///
+    /// Returns the number of items discarded according to the policy.
+    pub fn push_slice(&mut self, slice: &[C::Item]) -> usize {
+        slice
+            .iter()
+            .filter(|x| self.push((*x).clone()).is_some())
+            .count()
+    }
+}
+
+impl<C: Container + ?Sized, L: Length, P: OverflowPolicy> Extend<C::Item>
+    for WithPolicy<GenericVec<C, L>, P>
+{
+    fn extend<I: IntoIterator<Item = C::Item>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+/// Writes all bytes according to the policy, only [`Reject`] policy may write less than requested.
+#[cfg(feature = "std")]
+impl<C: Container<Item = u8> + ?Sized, L: Length, P: OverflowPolicy> std::io::Write
+    for WithPolicy<GenericVec<C, L>, P>
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if P::ACTION == Overflow::Reject {
+            return std::io::Write::write(&mut self.inner, buf);
+        }
+        for byte in buf {
+            self.push(*byte);
+        }
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length, P: OverflowPolicy>
+    WithPolicy<GenericString<C, L>, P>
+{
+    /// Inserts a character into the string at a byte position.
+    ///
+    /// Returns the number of characters discarded according to the policy, including the inserted one.
+    ///
+    /// *Panics if `index` is not on a char boundary.*
+    pub fn insert(&mut self, mut index: usize, c: char) -> usize {
+        let string = &mut self.inner;
+        assert!(string.is_char_boundary(index));
+        if c.len_utf8() > string.capacity() && P::ACTION != Overflow::Panic {
+            // The character doesn't fit even into an empty string.
+            return 1;
+        }
+        let mut discarded = 0;
+        while string.remaining() < c.len_utf8() {
+            match P::ACTION {
+                Overflow::Reject => return discarded + 1,
+                Overflow::Panic => panic!("string capacity exceeded"),
+                Overflow::OverwriteLast => match string.pop() {
+                    Some(_) => index = index.min(string.len()),
+                    None => return discarded + 1,
+                },
+                Overflow::EvictOldest => {
+                    if index == 0 {
+                        return discarded + 1;
+                    }
+                    index -= string.remove(0).len_utf8();
+                }
+            }
+            discarded += 1;
+        }
+        string.insert(index, c).unwrap();
+        discarded
+    }
+
+    /// Appends a character to the end of the string.
+    ///
+    /// Returns the number of characters discarded according to the policy, including the pushed one.
+    pub fn push(&mut self, c: char) -> usize {
+        self.insert(self.inner.len(), c)
+    }
+
+    /// Appends a string slice to the end of the string.
+    ///
+    /// Returns the number of characters discarded according to the policy, including the pushed ones.
+    ///
+    /// For [`Reject`] policy characters are pushed until the first one that doesn't fit.
+    pub fn push_str(&mut self, s: &str) -> usize {
+        self.push_chars(s.chars())
+    }
+
+    fn push_chars<I: Iterator<Item = char>>(&mut self, mut iter: I) -> usize {
+        let mut discarded = 0;
+        while let Some(c) = iter.next() {
+            let count = self.push(c);
+            discarded += count;
+            if count != 0 && P::ACTION == Overflow::Reject {
+                return discarded + iter.count();
+            }
+        }
+        discarded
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        self.inner.pop()
+    }
+    pub fn remove(&mut self, index: usize) -> char {
+        self.inner.remove(index)
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+    pub fn as_mut_str(&mut self) -> &mut str {
+        self.inner.as_mut_str()
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length, P: OverflowPolicy> Extend<char>
+    for WithPolicy<GenericString<C, L>, P>
+{
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.push_chars(iter.into_iter());
+    }
+}
+
+/// Writes strings according to the policy, fails only for [`Reject`] policy if not all characters are written.
+impl<C: Container<Item = u8> + ?Sized, L: Length, P: OverflowPolicy> fmt::Write
+    for WithPolicy<GenericString<C, L>, P>
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.push(c) != 0 && P::ACTION == Overflow::Reject {
+                return Err(fmt::Error);
+            }
+        }
+        Ok(())
+    }
+}
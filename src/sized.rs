@@ -1,6 +1,6 @@
 use crate::{
-    traits::{Container, Length},
-    GenericVec, IntoIter,
+    traits::{Container, DefaultContainer, Length, UninitSlot},
+    GenericString, GenericVec, IntoIter,
 };
 use core::{iter::IntoIterator, mem::ManuallyDrop, ptr};
 
@@ -29,6 +29,89 @@ impl<C: Container, L: Length> GenericVec<C, L> {
     }
 }
 
+impl<C: Container, L: Length> GenericVec<C, L> {
+    /// Moves items into a vector with another container and length type.
+    ///
+    /// If items don't fit into the new container or length type then the vector is returned back.
+    pub fn try_convert<D: DefaultContainer<Item = C::Item>, K: Length>(
+        mut self,
+    ) -> Result<GenericVec<D, K>, Self> {
+        let mut other = GenericVec::<D, K>::new();
+        let len = match K::from_usize(self.len()) {
+            Some(len) if self.len() <= other.capacity() => len,
+            _ => return Err(self),
+        };
+        unsafe {
+            // Slots have the same layout as items.
+            ptr::copy_nonoverlapping(
+                self.data.as_ref().as_ptr() as *const C::Item,
+                other.data.as_mut().as_mut_ptr() as *mut C::Item,
+                self.len(),
+            );
+            self.len = L::zero();
+        }
+        other.len = len;
+        Ok(other)
+    }
+}
+
+impl<S: UninitSlot, const N: usize, L: Length> GenericVec<[S; N], L> {
+    /// Moves items into a vector with capacity `M`.
+    ///
+    /// Fails to compile if `M` is less than `N`:
+    ///
+    /// ```compile_fail
+    /// # use stavec::StaticVec;
+    /// let vec = StaticVec::<i32, 8>::new();
+    /// let vec: StaticVec<i32, 4> = vec.resize_capacity();
+    /// ```
+    pub fn resize_capacity<const M: usize>(self) -> GenericVec<[S; M], L> {
+        const { assert!(M >= N, "new capacity must not be less than the old one") };
+        match self.try_convert() {
+            Ok(other) => other,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Moves items into a vector with capacity `M`.
+    ///
+    /// If items don't fit then the vector is returned back.
+    pub fn try_into_capacity<const M: usize>(self) -> Result<GenericVec<[S; M], L>, Self> {
+        self.try_convert()
+    }
+}
+
+impl<C: Container<Item = u8>, L: Length> GenericString<C, L> {
+    /// Moves the string into a string with another container and length type.
+    ///
+    /// If the string doesn't fit into the new container or length type then it is returned back.
+    pub fn try_convert<D: DefaultContainer<Item = u8>, K: Length>(
+        self,
+    ) -> Result<GenericString<D, K>, Self> {
+        match self.into_bytes().try_convert() {
+            Ok(bytes) => Ok(unsafe { GenericString::from_utf8_unchecked(bytes) }),
+            Err(bytes) => Err(unsafe { GenericString::from_utf8_unchecked(bytes) }),
+        }
+    }
+}
+
+impl<S: UninitSlot<Item = u8>, const N: usize, L: Length> GenericString<[S; N], L> {
+    /// Moves the string into a string with capacity `M`.
+    ///
+    /// Fails to compile if `M` is less than `N`.
+    pub fn resize_capacity<const M: usize>(self) -> GenericString<[S; M], L> {
+        let bytes = self.into_bytes().resize_capacity();
+        unsafe { GenericString::from_utf8_unchecked(bytes) }
+    }
+
+    /// Moves the string into a string with capacity `M`.
+    ///
+    /// If the string doesn't fit then it is returned back.
+    pub fn try_into_capacity<const M: usize>(self) -> Result<GenericString<[S; M], L>, Self> {
+        self.try_convert()
+    }
+}
+
 impl<C: Container, L: Length> IntoIterator for GenericVec<C, L> {
     type Item = C::Item;
     type IntoIter = IntoIter<C, L>;
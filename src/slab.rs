@@ -0,0 +1,265 @@
+use crate::traits::{Container, DefaultContainer, Slot, UninitSlot};
+use core::{fmt, mem, mem::MaybeUninit};
+
+/// Stable key of an item in [`GenericSlab`].
+///
+/// Contains generation of the entry, so the key of a removed item doesn't refer to a new item inserted at the same place.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SlabKey {
+    index: usize,
+    generation: u32,
+}
+
+impl SlabKey {
+    /// Index of the entry in the slab.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// State of a slab item slot.
+///
+/// Entries are always initialized, so they are slots for themselves.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct SlabEntry {
+    /// Generation is odd when the entry is occupied.
+    generation: u32,
+    /// Index of the next vacant entry, valid only when the entry is vacant.
+    next_free: usize,
+}
+
+impl SlabEntry {
+    fn is_occupied(&self) -> bool {
+        self.generation % 2 == 1
+    }
+}
+
+unsafe impl Slot for SlabEntry {
+    type Item = SlabEntry;
+
+    fn new(item: Self) -> Self {
+        item
+    }
+    unsafe fn assume_init(self) -> Self::Item {
+        self
+    }
+    unsafe fn assume_init_read(&self) -> Self::Item {
+        *self
+    }
+}
+impl UninitSlot for SlabEntry {
+    fn uninit() -> Self {
+        Self::default()
+    }
+}
+
+/// Storage which keeps items at the same place until they are removed.
+///
+/// The type parametrized by:
+/// + `C` - container of items.
+/// + `E` - container of entries, must have the same length as `C`.
+///
+/// Vacant entries are reused in O(1) via free list.
+/// Each entry has a generation counter, so stale keys are detected
+/// (unless the entry was reused `2^31` times).
+pub struct GenericSlab<C: Container, E: Container<Slot = SlabEntry>> {
+    len: usize,
+    /// Index of the first vacant entry, capacity if there are no vacant entries.
+    free: usize,
+    entries: E,
+    data: C,
+}
+
+/// Slab with static capacity of `N` items.
+pub type StaticSlab<T, const N: usize> = GenericSlab<[MaybeUninit<T>; N], [SlabEntry; N]>;
+
+impl<C: DefaultContainer, E: DefaultContainer<Slot = SlabEntry>> GenericSlab<C, E> {
+    /// Create a new empty slab.
+    pub fn new() -> Self {
+        Self::from_empty(C::default(), E::default())
+    }
+}
+
+impl<C: DefaultContainer, E: DefaultContainer<Slot = SlabEntry>> Default for GenericSlab<C, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Container, E: Container<Slot = SlabEntry>> GenericSlab<C, E> {
+    /// Creates an empty slab over the containers.
+    ///
+    /// Contents of `data` are treated as un-initialized, previous contents of `entries` are discarded.
+    ///
+    /// *Panics if containers have different lengths.*
+    pub fn from_empty(data: C, mut entries: E) -> Self {
+        assert_eq!(data.as_ref().len(), entries.as_ref().len());
+        for (index, entry) in entries.as_mut().iter_mut().enumerate() {
+            *entry = SlabEntry {
+                generation: 0,
+                next_free: index + 1,
+            };
+        }
+        Self {
+            len: 0,
+            free: 0,
+            entries,
+            data,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.data.as_ref().len()
+    }
+
+    /// Number of items in the slab.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the slab is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Checks whether the slab is full.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Inserts an item into a vacant entry and returns its key.
+    ///
+    /// If the slab is full then the item is returned back.
+    pub fn insert(&mut self, value: C::Item) -> Result<SlabKey, C::Item> {
+        if self.free == self.capacity() {
+            return Err(value);
+        }
+        let index = self.free;
+        let entry = &mut self.entries.as_mut()[index];
+        self.free = entry.next_free;
+        entry.generation = entry.generation.wrapping_add(1);
+        let generation = entry.generation;
+        let _ = mem::replace(&mut self.data.as_mut()[index], C::Slot::new(value));
+        self.len += 1;
+        Ok(SlabKey { index, generation })
+    }
+
+    fn entry(&self, key: SlabKey) -> Option<&SlabEntry> {
+        self.entries
+            .as_ref()
+            .get(key.index)
+            .filter(|entry| entry.generation == key.generation && entry.is_occupied())
+    }
+
+    /// # Safety
+    ///
+    /// Item at `index` must be occupied.
+    unsafe fn item(&self, index: usize) -> &C::Item {
+        // Slots have the same layout as items.
+        &*(self.data.as_ref().get_unchecked(index) as *const C::Slot as *const C::Item)
+    }
+
+    /// # Safety
+    ///
+    /// Item at `index` must be occupied.
+    unsafe fn item_mut(&mut self, index: usize) -> &mut C::Item {
+        &mut *(self.data.as_mut().get_unchecked_mut(index) as *mut C::Slot as *mut C::Item)
+    }
+
+    /// Checks whether the key refers to an item in the slab.
+    pub fn contains(&self, key: SlabKey) -> bool {
+        self.entry(key).is_some()
+    }
+
+    /// Returns item by key or `None` if the key is stale.
+    pub fn get(&self, key: SlabKey) -> Option<&C::Item> {
+        self.entry(key)?;
+        Some(unsafe { self.item(key.index) })
+    }
+
+    /// Returns mutable item by key or `None` if the key is stale.
+    pub fn get_mut(&mut self, key: SlabKey) -> Option<&mut C::Item> {
+        self.entry(key)?;
+        Some(unsafe { self.item_mut(key.index) })
+    }
+
+    /// Removes item by key and returns it or `None` if the key is stale.
+    pub fn remove(&mut self, key: SlabKey) -> Option<C::Item> {
+        self.entry(key)?;
+        let entry = &mut self.entries.as_mut()[key.index];
+        entry.generation = entry.generation.wrapping_add(1);
+        entry.next_free = self.free;
+        self.free = key.index;
+        self.len -= 1;
+        Some(unsafe { self.data.as_ref()[key.index].assume_init_read() })
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        let guard = ClearGuard(self);
+        for index in 0..guard.0.capacity() {
+            let generation = guard.0.entries.as_ref()[index].generation;
+            guard.0.remove(SlabKey { index, generation });
+        }
+        mem::forget(guard);
+    }
+
+    /// Returns iterator over occupied entries in order of their indices.
+    pub fn iter(&self) -> impl Iterator<Item = (SlabKey, &C::Item)> + '_ {
+        self.entries
+            .as_ref()
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.is_occupied())
+            .map(|(index, entry)| {
+                let key = SlabKey {
+                    index,
+                    generation: entry.generation,
+                };
+                (key, unsafe { self.item(index) })
+            })
+    }
+
+    /// Returns iterator over occupied entries with mutable items in order of their indices.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (SlabKey, &mut C::Item)> + '_ {
+        self.entries
+            .as_ref()
+            .iter()
+            .zip(self.data.as_mut().iter_mut())
+            .enumerate()
+            .filter(|(_, (entry, _))| entry.is_occupied())
+            .map(|(index, (entry, slot))| {
+                let key = SlabKey {
+                    index,
+                    generation: entry.generation,
+                };
+                (key, unsafe { &mut *(slot as *mut C::Slot as *mut C::Item) })
+            })
+    }
+}
+
+/// Continues clearing if an item panics on drop.
+struct ClearGuard<'a, C: Container, E: Container<Slot = SlabEntry>>(&'a mut GenericSlab<C, E>);
+
+impl<C: Container, E: Container<Slot = SlabEntry>> Drop for ClearGuard<'_, C, E> {
+    fn drop(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl<C: Container, E: Container<Slot = SlabEntry>> Drop for GenericSlab<C, E> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<C: Container, E: Container<Slot = SlabEntry>> fmt::Debug for GenericSlab<C, E>
+where
+    C::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_map()
+            .entries(self.iter().map(|(key, value)| (key.index, value)))
+            .finish()
+    }
+}
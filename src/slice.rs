@@ -0,0 +1,45 @@
+use crate::{utils::slice_assume_init_mut, GenericVec};
+use core::mem::MaybeUninit;
+
+/// Vector over borrowed uninitialized memory, e.g. DMA buffer or arena chunk.
+///
+/// The vector does not own the buffer, so its capacity is equal to the slice length.
+///
+/// If the vector is leaked (e.g. by [`mem::forget`](`core::mem::forget`)) then its items are leaked too,
+/// but the buffer remains in a valid state because it is still a slice of [`MaybeUninit`].
+pub type SliceVec<'a, T> = GenericVec<&'a mut [MaybeUninit<T>]>;
+
+impl<'a, T> SliceVec<'a, T> {
+    /// Creates an empty vector over uninitialized slice.
+    pub fn from_uninit_slice(slice: &'a mut [MaybeUninit<T>]) -> Self {
+        Self::from_empty(slice)
+    }
+
+    /// Creates a vector over slice which first `len` items are already initialized.
+    ///
+    /// Initialized items are owned by the vector since then and will be dropped with it.
+    ///
+    /// *Panics if `len` is greater than the slice length.*
+    ///
+    /// # Safety
+    ///
+    /// Slice items with indices lower than `len` must be initialized.
+    pub unsafe fn from_init_slice_with_len(slice: &'a mut [MaybeUninit<T>], len: usize) -> Self {
+        assert!(len <= slice.len());
+        Self::from_raw_parts(slice, len)
+    }
+
+    /// Converts the vector into a slice of its items.
+    ///
+    /// Items are not dropped and remain in the buffer, so the caller is responsible for dropping them.
+    pub fn into_init_slice(self) -> &'a mut [T] {
+        let (data, len) = unsafe { self.into_raw_parts() };
+        unsafe { slice_assume_init_mut(data.get_unchecked_mut(..len)) }
+    }
+
+    /// Drops all items and returns the whole underlying buffer back.
+    pub fn into_uninit_slice(mut self) -> &'a mut [MaybeUninit<T>] {
+        self.clear();
+        unsafe { self.into_raw_parts() }.0
+    }
+}
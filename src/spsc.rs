@@ -0,0 +1,304 @@
+use crate::{
+    sync::{AtomicUsize, Ordering, SlotTracker},
+    traits::{Container, DefaultContainer, Slot},
+    utils::slice_assume_init_mut,
+};
+use core::{cell::UnsafeCell, mem::MaybeUninit, ptr};
+
+/// Single-producer single-consumer lock-free bounded queue.
+///
+/// The queue should be [`split`](`Self::split`) into [`Producer`] and [`Consumer`]
+/// which could be used from different threads or interrupt handlers.
+pub struct GenericSpsc<C: Container> {
+    /// Position of the first item in range `0..(2 * capacity)`.
+    head: AtomicUsize,
+    /// Position after the last item in range `0..(2 * capacity)`.
+    tail: AtomicUsize,
+    capacity: usize,
+    tracker: SlotTracker,
+    data: UnsafeCell<C>,
+}
+
+/// Single-producer single-consumer queue with static capacity.
+pub type StaticSpsc<T, const N: usize> = GenericSpsc<[MaybeUninit<T>; N]>;
+
+impl<T, const N: usize> StaticSpsc<T, N> {
+    /// Create a new empty queue.
+    ///
+    /// Could be used to initialize a `static`.
+    #[cfg(not(loom))]
+    pub const fn new() -> Self {
+        assert!(N <= usize::MAX / 2);
+        Self {
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            capacity: N,
+            tracker: SlotTracker::new(N),
+            data: UnsafeCell::new([const { MaybeUninit::uninit() }; N]),
+        }
+    }
+    /// Create a new empty queue.
+    #[cfg(loom)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: DefaultContainer> Default for GenericSpsc<C> {
+    fn default() -> Self {
+        Self::from_empty(C::default())
+    }
+}
+
+impl<C: Container> GenericSpsc<C> {
+    /// Creates an empty queue over the container.
+    ///
+    /// *Panics if container length is greater than `usize::MAX / 2`.*
+    pub fn from_empty(data: C) -> Self {
+        let capacity = data.as_ref().len();
+        assert!(capacity <= usize::MAX / 2);
+        Self {
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            capacity,
+            tracker: SlotTracker::new(capacity),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of items in the queue.
+    ///
+    /// The value may be already outdated if the queue is being used concurrently.
+    pub fn len(&self) -> usize {
+        self.distance(
+            self.head.load(Ordering::Acquire),
+            self.tail.load(Ordering::Acquire),
+        )
+    }
+
+    /// Checks whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks whether the queue is full.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity
+    }
+
+    /// Splits the queue into producer and consumer.
+    pub fn split(&mut self) -> (Producer<'_, C>, Consumer<'_, C>) {
+        let slots = self.data.get_mut().as_mut().as_mut_ptr();
+        let queue = &*self;
+        (Producer { queue, slots }, Consumer { queue, slots })
+    }
+
+    fn distance(&self, head: usize, tail: usize) -> usize {
+        if tail >= head {
+            tail - head
+        } else {
+            tail + 2 * self.capacity - head
+        }
+    }
+
+    fn advance(&self, pos: usize, count: usize) -> usize {
+        (pos + count) % (2 * self.capacity)
+    }
+
+    fn index(&self, pos: usize) -> usize {
+        if pos < self.capacity {
+            pos
+        } else {
+            pos - self.capacity
+        }
+    }
+}
+
+impl<C: Container> Drop for GenericSpsc<C> {
+    fn drop(&mut self) {
+        let head = self.head.load(Ordering::Acquire);
+        let len = self.distance(head, self.tail.load(Ordering::Acquire));
+        let start = self.index(head);
+        let first_len = len.min(self.capacity - start);
+        let (wrapped, first) = self.data.get_mut().as_mut().split_at_mut(start);
+        unsafe {
+            // The wrapped part is still dropped if an item of the first part panics.
+            let _wrapped = DropSlice(slice_assume_init_mut(&mut wrapped[..(len - first_len)]));
+            ptr::drop_in_place(slice_assume_init_mut(&mut first[..first_len]));
+        }
+    }
+}
+
+/// Drops items of the slice when dropped.
+struct DropSlice<'a, T>(&'a mut [T]);
+
+impl<T> Drop for DropSlice<'_, T> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.0) };
+    }
+}
+
+/// Publishes position change even if panic occurred in the middle of batch operation.
+struct Commit<'a, C: Container> {
+    queue: &'a GenericSpsc<C>,
+    pos: &'a AtomicUsize,
+    start: usize,
+    count: usize,
+}
+
+impl<C: Container> Drop for Commit<'_, C> {
+    fn drop(&mut self) {
+        if self.count > 0 {
+            self.pos.store(
+                self.queue.advance(self.start, self.count),
+                Ordering::Release,
+            );
+        }
+    }
+}
+
+/// Producer half of the queue.
+pub struct Producer<'a, C: Container> {
+    queue: &'a GenericSpsc<C>,
+    slots: *mut C::Slot,
+}
+
+unsafe impl<C: Container> Send for Producer<'_, C> where C::Item: Send {}
+
+impl<C: Container> Producer<'_, C> {
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
+    /// Number of free places in the queue.
+    ///
+    /// Actual number may only grow until the next push.
+    pub fn remaining(&self) -> usize {
+        self.queue.capacity() - self.queue.len()
+    }
+
+    /// Checks whether the queue is full.
+    pub fn is_full(&self) -> bool {
+        self.queue.is_full()
+    }
+
+    /// Appends a new item to the end of the queue.
+    ///
+    /// If the queue is full then the item is returned.
+    pub fn push(&mut self, item: C::Item) -> Result<(), C::Item> {
+        let queue = self.queue;
+        let tail = queue.tail.load(Ordering::Relaxed);
+        let head = queue.head.load(Ordering::Acquire);
+        if queue.distance(head, tail) == queue.capacity {
+            return Err(item);
+        }
+        let index = queue.index(tail);
+        queue.tracker.write(index, || unsafe {
+            ptr::write(self.slots.add(index), C::Slot::new(item))
+        });
+        queue.tail.store(queue.advance(tail, 1), Ordering::Release);
+        Ok(())
+    }
+}
+
+impl<C: Container> Producer<'_, C>
+where
+    C::Item: Clone,
+{
+    /// Clones and appends items from the slice until slice ends or the queue is full.
+    ///
+    /// Items are published to consumer all at once. Returns the number of appended items.
+    pub fn push_slice(&mut self, slice: &[C::Item]) -> usize {
+        let queue = self.queue;
+        let tail = queue.tail.load(Ordering::Relaxed);
+        let head = queue.head.load(Ordering::Acquire);
+        let count = slice.len().min(queue.capacity - queue.distance(head, tail));
+        let mut commit = Commit {
+            queue,
+            pos: &queue.tail,
+            start: tail,
+            count: 0,
+        };
+        for item in &slice[..count] {
+            let index = queue.index(queue.advance(tail, commit.count));
+            queue.tracker.write(index, || unsafe {
+                ptr::write(self.slots.add(index), C::Slot::new(item.clone()))
+            });
+            commit.count += 1;
+        }
+        count
+    }
+}
+
+/// Consumer half of the queue.
+pub struct Consumer<'a, C: Container> {
+    queue: &'a GenericSpsc<C>,
+    slots: *mut C::Slot,
+}
+
+unsafe impl<C: Container> Send for Consumer<'_, C> where C::Item: Send {}
+
+impl<C: Container> Consumer<'_, C> {
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
+    /// The number of items in the queue.
+    ///
+    /// Actual number may only grow until the next pop.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Checks whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Removes and returns the first item of the queue.
+    ///
+    /// If the queue is empty then `None` is returned.
+    pub fn pop(&mut self) -> Option<C::Item> {
+        let queue = self.queue;
+        let head = queue.head.load(Ordering::Relaxed);
+        let tail = queue.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        let index = queue.index(head);
+        let item = queue.tracker.read(index, || unsafe {
+            ptr::read(self.slots.add(index)).assume_init()
+        });
+        queue.head.store(queue.advance(head, 1), Ordering::Release);
+        Some(item)
+    }
+
+    /// Moves items from the queue into the slice until slice ends or the queue is empty.
+    ///
+    /// Previous slice items are dropped. Returns the number of moved items.
+    pub fn pop_into(&mut self, slice: &mut [C::Item]) -> usize {
+        let queue = self.queue;
+        let head = queue.head.load(Ordering::Relaxed);
+        let tail = queue.tail.load(Ordering::Acquire);
+        let count = slice.len().min(queue.distance(head, tail));
+        let mut commit = Commit {
+            queue,
+            pos: &queue.head,
+            start: head,
+            count: 0,
+        };
+        for dst in &mut slice[..count] {
+            let index = queue.index(queue.advance(head, commit.count));
+            let item = queue.tracker.read(index, || unsafe {
+                ptr::read(self.slots.add(index)).assume_init()
+            });
+            commit.count += 1;
+            *dst = item;
+        }
+        count
+    }
+}
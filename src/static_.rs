@@ -1,4 +1,7 @@
-use crate::GenericVec;
+use crate::{
+    error::{FullError, IntoArrayError},
+    GenericVec,
+};
 use core::{
     convert::{AsMut, AsRef},
     iter::IntoIterator,
@@ -33,6 +36,31 @@ impl<T, const N: usize> StaticVec<T, N> {
         assert!(M <= N); // TODO: Use static assert.
         Self::from_iter_until_full(IntoIterator::into_iter(array))
     }
+
+    /// Constructs a new vector from iterator of arrays.
+    ///
+    /// Fails if items don't fit into the vector.
+    pub fn try_from_chunks<const K: usize, I: IntoIterator<Item = [T; K]>>(
+        iter: I,
+    ) -> Result<Self, FullError> {
+        let mut self_ = Self::new();
+        for chunk in iter {
+            self_.push_array(chunk).map_err(|_| FullError)?;
+        }
+        Ok(self_)
+    }
+
+    /// Converts the vector into an array of `M` items.
+    ///
+    /// Succeeds only if the vector length is exactly `M`, otherwise the vector is returned back.
+    pub fn try_into_array<const M: usize>(self) -> Result<[T; M], IntoArrayError<Self>> {
+        const { assert!(M <= N, "array size must not exceed vector capacity") };
+        if self.len() == M {
+            unsafe { Ok(ptr::read(self.into_raw_parts().0.as_ptr() as *const [T; M])) }
+        } else {
+            Err(IntoArrayError(self))
+        }
+    }
 }
 
 impl<T: Clone, const N: usize> StaticVec<T, N> {
@@ -47,6 +75,14 @@ impl<T: Clone, const N: usize> StaticVec<T, N> {
         }
         self_
     }
+
+    /// Converts the vector into an array filling remaining space with clones of `fill`.
+    pub fn into_array_padded(mut self, fill: T) -> [T; N] {
+        while !self.is_full() {
+            unsafe { self.push_unchecked(fill.clone()) };
+        }
+        unsafe { ptr::read(self.into_raw_parts().0.as_ptr() as *const [T; N]) }
+    }
 }
 
 impl<T, const N: usize> AsRef<GenericVec<[MaybeUninit<T>]>> for StaticVec<T, N> {
@@ -69,16 +105,12 @@ impl<T, const N: usize> From<[T; N]> for StaticVec<T, N> {
 }
 
 impl<T, const N: usize> TryFrom<StaticVec<T, N>> for [T; N] {
-    type Error = ();
+    type Error = IntoArrayError<StaticVec<T, N>>;
 
     /// Converts the static vector into an array.
     ///
     /// This only succeeds if the vector is full and thus actually contains `N` initialized elements.
     fn try_from(vec: StaticVec<T, N>) -> Result<Self, Self::Error> {
-        if vec.is_full() {
-            unsafe { Ok(ptr::read(vec.into_raw_parts().0.as_ptr() as *const [T; N])) }
-        } else {
-            Err(())
-        }
+        vec.try_into_array()
     }
 }
@@ -12,8 +12,16 @@ use core::{
     str::{from_utf8, from_utf8_unchecked, from_utf8_unchecked_mut, Utf8Error},
 };
 
+/// The greatest char boundary in `s` that is not greater than `index`.
+pub(crate) fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
 #[repr(transparent)]
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
 pub struct GenericString<C: Container<Item = u8> + ?Sized, L: Length = usize> {
     bytes: GenericVec<C, L>,
 }
@@ -40,6 +48,24 @@ impl<C: DefaultContainer<Item = u8>, L: Length> GenericString<C, L> {
         Ok(self_)
     }
 }
+impl<C: DefaultContainer<Item = u8>, L: Length> GenericString<C, L> {
+    /// Creates a string from the longest prefix of `s` that fits.
+    ///
+    /// Returns the string and the number of dropped bytes.
+    pub fn from_str_truncated(s: &str) -> (Self, usize) {
+        let mut self_ = Self::default();
+        let dropped = self_.push_str_truncated(s);
+        (self_, dropped)
+    }
+    /// Creates a string from `s` truncating it and appending `marker` (e.g. `"..."`) if `s` doesn't fit.
+    ///
+    /// Returns the string and the number of dropped bytes of `s`.
+    pub fn from_str_truncated_with(s: &str, marker: &str) -> (Self, usize) {
+        let mut self_ = Self::default();
+        let dropped = self_.push_str_truncated_with(s, marker);
+        (self_, dropped)
+    }
+}
 impl<C: DefaultContainer<Item = u8>, L: Length> TryFrom<&str> for GenericString<C, L> {
     type Error = FullError;
 
@@ -62,6 +88,21 @@ impl<C: DefaultContainer<Item = u8>, L: Length> TryFrom<GenericVec<C, L>> for Ge
     }
 }
 
+impl<C: Container<Item = u8>, L: Length> GenericString<C, L> {
+    /// Creates a string from the vector without checking that it contains valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// Vector contents must be valid UTF-8.
+    pub unsafe fn from_utf8_unchecked(vec: GenericVec<C, L>) -> Self {
+        Self { bytes: vec }
+    }
+    /// Converts the string into underlying vector.
+    pub fn into_bytes(self) -> GenericVec<C, L> {
+        self.bytes
+    }
+}
+
 impl<C: Container<Item = u8> + ?Sized, L: Length> GenericString<C, L> {
     pub fn capacity(&self) -> usize {
         self.bytes.capacity()
@@ -113,6 +154,66 @@ impl<C: Container<Item = u8> + ?Sized, L: Length> GenericString<C, L> {
     pub fn push_str(&mut self, s: &str) -> Result<(), FullError> {
         self.bytes.push_slice(s.as_bytes())
     }
+
+    /// Appends a character if it fits.
+    ///
+    /// Returns the number of dropped bytes, i.e. zero or the length of the character.
+    pub fn push_truncated(&mut self, c: char) -> usize {
+        match self.push(c) {
+            Ok(()) => 0,
+            Err(FullError) => c.len_utf8(),
+        }
+    }
+    /// Appends the longest prefix of `s` that fits and ends at a char boundary.
+    ///
+    /// Returns the number of dropped bytes.
+    pub fn push_str_truncated(&mut self, s: &str) -> usize {
+        let len = floor_char_boundary(s, self.remaining());
+        self.push_str(&s[..len]).unwrap();
+        s.len() - len
+    }
+    /// Appends `s` if it fits, otherwise appends the longest prefix of `s` followed by `marker` (e.g. `"..."`).
+    ///
+    /// If even the `marker` doesn't fit then it is truncated too.
+    /// Returns the number of dropped bytes of `s`.
+    pub fn push_str_truncated_with(&mut self, s: &str, marker: &str) -> usize {
+        if s.len() <= self.remaining() {
+            self.push_str(s).unwrap();
+            return 0;
+        }
+        let len = floor_char_boundary(s, self.remaining().saturating_sub(marker.len()));
+        self.push_str(&s[..len]).unwrap();
+        self.push_str_truncated(marker);
+        s.len() - len
+    }
+
+    /// Removes the last character from the string and returns it.
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.bytes.truncate(self.len() - c.len_utf8());
+        Some(c)
+    }
+
+    /// Inserts a character into the string at a byte position.
+    ///
+    /// *Panics if `index` is not on a char boundary.*
+    pub fn insert(&mut self, index: usize, c: char) -> Result<(), FullError> {
+        assert!(self.as_str().is_char_boundary(index));
+        self.push(c)?;
+        self.bytes.as_mut_slice()[index..].rotate_right(c.len_utf8());
+        Ok(())
+    }
+
+    /// Removes a character from the string at a byte position and returns it.
+    ///
+    /// *Panics if `index` is not on a char boundary or is out of bounds.*
+    pub fn remove(&mut self, index: usize) -> char {
+        let c = self.as_str()[index..].chars().next().unwrap();
+        let new_len = self.len() - c.len_utf8();
+        self.bytes.as_mut_slice()[index..].rotate_left(c.len_utf8());
+        self.bytes.truncate(new_len);
+        c
+    }
 }
 
 impl<C: Container<Item = u8> + ?Sized, L: Length> Hash for GenericString<C, L> {
@@ -133,6 +234,13 @@ impl<C: Container<Item = u8> + ?Sized, L: Length> fmt::Display for GenericString
     }
 }
 
+/// Fails if the string doesn't fit, nothing is written in that case.
+impl<C: Container<Item = u8> + ?Sized, L: Length> fmt::Write for GenericString<C, L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|FullError| fmt::Error)
+    }
+}
+
 impl<C: Container<Item = u8> + ?Sized, L: Length> Deref for GenericString<C, L> {
     type Target = str;
 
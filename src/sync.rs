@@ -0,0 +1,46 @@
+#[cfg(all(not(loom), target_has_atomic = "ptr"))]
+pub(crate) use core::sync::atomic::AtomicBool;
+#[cfg(not(loom))]
+pub(crate) use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Tracks accesses to container slots to detect data races when testing with `loom`, does nothing otherwise.
+pub(crate) struct SlotTracker {
+    #[cfg(loom)]
+    cells: std::vec::Vec<loom::cell::UnsafeCell<()>>,
+}
+
+impl SlotTracker {
+    #[cfg(not(loom))]
+    #[allow(unused_variables)]
+    pub(crate) const fn new(capacity: usize) -> Self {
+        Self {}
+    }
+    #[cfg(loom)]
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            cells: (0..capacity)
+                .map(|_| loom::cell::UnsafeCell::new(()))
+                .collect(),
+        }
+    }
+
+    /// Marks that the slot at `index` is read during `f` call.
+    #[allow(unused_variables)]
+    pub(crate) fn read<R, F: FnOnce() -> R>(&self, index: usize, f: F) -> R {
+        #[cfg(loom)]
+        return self.cells[index].with(|_| f());
+        #[cfg(not(loom))]
+        f()
+    }
+
+    /// Marks that the slot at `index` is written during `f` call.
+    #[allow(unused_variables)]
+    pub(crate) fn write<R, F: FnOnce() -> R>(&self, index: usize, f: F) -> R {
+        #[cfg(loom)]
+        return self.cells[index].with_mut(|_| f());
+        #[cfg(not(loom))]
+        f()
+    }
+}
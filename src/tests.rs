@@ -3,6 +3,9 @@ use core::convert::TryInto;
 use crate::StaticVec;
 use core::iter::IntoIterator;
 
+#[cfg(all(feature = "std", not(loom)))]
+mod soundness;
+
 #[test]
 fn empty() {
     let mut v = StaticVec::<i32, 4>::new();
@@ -251,3 +254,1094 @@ fn array_conversion() {
     let output: Result<[i32; 4], _> = v.clone().try_into();
     assert!(output.is_err());
 }
+
+#[test]
+fn slice_vec() {
+    use crate::SliceVec;
+    use core::mem::MaybeUninit;
+
+    let mut buf = [MaybeUninit::<i32>::uninit(); 4];
+    let mut v = SliceVec::from_uninit_slice(&mut buf);
+    assert_eq!(v.capacity(), 4);
+    v.push_slice(&[0, 1, 2]).unwrap();
+    assert_eq!(v.pop(), Some(2));
+    assert_eq!(v.into_init_slice(), &mut [0, 1]);
+
+    let mut v = unsafe { SliceVec::from_init_slice_with_len(&mut buf, 2) };
+    assert_eq!(v.len(), 2);
+    v.push_slice(&[2, 3]).unwrap();
+    assert_eq!(v.push(4), Err(4));
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn slice_vec_drop() {
+    use crate::SliceVec;
+    use core::mem::MaybeUninit;
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let mut buf: [MaybeUninit<Rc<()>>; 4] = [(); 4].map(|()| MaybeUninit::uninit());
+    let mut v = SliceVec::from_uninit_slice(&mut buf);
+    v.push(rc.clone()).unwrap();
+    v.push(rc.clone()).unwrap();
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let buf = v.into_uninit_slice();
+    assert_eq!(buf.len(), 4);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+#[cfg(feature = "mmap")]
+fn mmap_persist() {
+    use crate::MmapVec;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("vec.bin");
+    {
+        let mut v = unsafe { MmapVec::<u32>::create(&path, 4) }.unwrap();
+        assert_eq!(v.capacity(), 4);
+        assert!(v.is_empty());
+        v.push_slice(&[1, 2, 3]).unwrap();
+        v.flush().unwrap();
+        v.push(4).unwrap();
+    }
+    {
+        let mut v = unsafe { MmapVec::<u32>::open(&path) }.unwrap();
+        assert_eq!(v, [1, 2, 3]);
+        v.push_slice(&[4, 5]).unwrap_err();
+        v.pop().unwrap();
+        v.flush().unwrap();
+    }
+    let v = unsafe { MmapVec::<u32>::open(&path) }.unwrap();
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+#[cfg(feature = "mmap")]
+fn mmap_invalid() {
+    use crate::MmapVec;
+    use std::fs;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("vec.bin");
+    unsafe { MmapVec::<u32>::create(&path, 4) }.unwrap();
+    assert!(unsafe { MmapVec::<u16>::open(&path) }.is_err());
+    assert!(unsafe { MmapVec::<u32>::open(&path) }.is_ok());
+
+    fs::write(&path, [0u8; 64]).unwrap();
+    assert!(unsafe { MmapVec::<u32>::open(&path) }.is_err());
+}
+
+#[test]
+fn zeroed_slots() {
+    use crate::GenericVec;
+
+    let mut v = GenericVec::<[u32; 4]>::new();
+    v.push_slice(&[1, 2]).unwrap();
+    assert_eq!(v.data(), &[1, 2, 0, 0]);
+    v.pop().unwrap();
+    assert_eq!(v, [1]);
+
+    let v = GenericVec::<[f64; 2], u8>::try_from_slice(&[0.5]).unwrap();
+    assert_eq!(v.data(), &[0.5, 0.0]);
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn pod_slots() {
+    use crate::{traits::PodSlot, GenericVec};
+
+    let mut v = GenericVec::<[PodSlot<[u16; 2]>; 3]>::new();
+    v.push([1, 2]).unwrap();
+    v.push([3, 4]).unwrap();
+    assert_eq!(v, [[1, 2], [3, 4]]);
+    let data = *v.data();
+    assert_eq!(data.map(|s| s.0), [[1, 2], [3, 4], [0, 0]]);
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn bytemuck_zeroed() {
+    use crate::GenericVec;
+
+    let v: StaticVec<i32, 4> = bytemuck::Zeroable::zeroed();
+    assert!(v.is_empty());
+    let v: GenericVec<[u16; 4], u16> = bytemuck::Zeroable::zeroed();
+    assert!(v.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "repr-c", feature = "bytemuck"))]
+fn bytemuck_bytes() {
+    use crate::{error::BytesError, GenericVec};
+
+    type V = GenericVec<[u32; 2], u32>;
+    let v = V::try_from_slice(&[1]).unwrap();
+    let bytes = v.as_bytes();
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(bytes[..4], 1u32.to_ne_bytes());
+    assert_eq!(V::try_from_bytes(bytes).unwrap(), &v);
+    assert_eq!(V::try_pod_read_unaligned(bytes).unwrap(), v);
+    assert_eq!(V::try_from_bytes(&bytes[1..]), Err(BytesError::Size));
+
+    let mut words = [3u32, 1, 2];
+    let bytes = bytemuck::bytes_of_mut(&mut words);
+    assert_eq!(V::try_from_bytes_mut(bytes), Err(BytesError::Length));
+    bytes[..4].copy_from_slice(&2u32.to_ne_bytes());
+    assert_eq!(V::try_from_bytes_mut(bytes).unwrap().pop(), Some(2));
+    assert_eq!(words[0], 1);
+}
+
+#[test]
+#[cfg(all(feature = "repr-c", feature = "zerocopy"))]
+fn zerocopy_bytes() {
+    use crate::{error::BytesError, GenericVec};
+
+    type V = GenericVec<[u8; 3], u8>;
+    let v = V::try_from_slice(&[1, 2]).unwrap();
+    assert_eq!(v.as_bytes(), [2, 1, 2, 0]);
+    assert_eq!(V::try_ref_from_bytes(&[1, 5, 6, 7]).unwrap(), &[5]);
+    assert_eq!(V::try_read_from_bytes(&[3, 5, 6, 7]).unwrap(), [5, 6, 7]);
+    assert_eq!(
+        V::try_read_from_bytes(&[4, 5, 6, 7]),
+        Err(BytesError::Length)
+    );
+    let mut bytes = [0, 0, 0, 0];
+    V::try_mut_from_bytes(&mut bytes).unwrap().push(9).unwrap();
+    assert_eq!(bytes, [1, 9, 0, 0]);
+}
+
+#[test]
+#[cfg(not(loom))]
+fn spsc() {
+    use crate::StaticSpsc;
+
+    let mut q = const { StaticSpsc::<i32, 3>::new() };
+    let (mut p, mut c) = q.split();
+    assert_eq!(c.pop(), None);
+    for i in 0..10 {
+        assert_eq!(p.push(2 * i), Ok(()));
+        assert_eq!(p.push(2 * i + 1), Ok(()));
+        assert_eq!(c.len(), 2);
+        assert_eq!(c.pop(), Some(2 * i));
+        assert_eq!(c.pop(), Some(2 * i + 1));
+        assert!(c.is_empty());
+    }
+    assert_eq!(p.push_slice(&[0, 1, 2, 3]), 3);
+    assert_eq!(p.push(3), Err(3));
+    assert!(p.is_full());
+
+    let mut buf = [-1; 2];
+    assert_eq!(c.pop_into(&mut buf), 2);
+    assert_eq!(buf, [0, 1]);
+    assert_eq!(p.remaining(), 2);
+    assert_eq!(p.push_slice(&[3, 4, 5]), 2);
+    assert_eq!(c.pop_into(&mut [0; 4]), 3);
+    assert!(q.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "std", not(loom)))]
+fn spsc_threads() {
+    use crate::StaticSpsc;
+    use std::{mem, rc::Rc, thread};
+
+    let mut q = StaticSpsc::<u32, 7>::new();
+    let (mut p, mut c) = q.split();
+    thread::scope(|s| {
+        s.spawn(move || {
+            let mut i = 0;
+            while i < 1000 {
+                i += p.push_slice(&[i, i + 1, i + 2][..(1000 - i).min(3) as usize]) as u32;
+            }
+        });
+        s.spawn(move || {
+            let mut buf = [0; 2];
+            let mut i = 0;
+            while i < 1000 {
+                let n = c.pop_into(&mut buf);
+                for x in &buf[..n] {
+                    assert_eq!(*x, i);
+                    i += 1;
+                }
+            }
+            assert_eq!(c.pop(), None);
+        });
+    });
+
+    let rc = Rc::new(());
+    let mut q = StaticSpsc::<Rc<()>, 4>::new();
+    q.split().0.push_slice(&[rc.clone(), rc.clone()]);
+    assert_eq!(Rc::strong_count(&rc), 3);
+    mem::drop(q);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+#[cfg(loom)]
+fn spsc_loom() {
+    use crate::StaticSpsc;
+    use loom::thread;
+    use std::boxed::Box;
+
+    loom::model(|| {
+        let q = Box::leak(Box::new(StaticSpsc::<u32, 2>::new()));
+        let (mut p, mut c) = q.split();
+        let t = thread::spawn(move || {
+            let mut i = 0;
+            while i < 3 {
+                if p.push(i).is_ok() {
+                    i += 1;
+                } else {
+                    thread::yield_now();
+                }
+            }
+        });
+        let mut i = 0;
+        while i < 3 {
+            match c.pop() {
+                Some(x) => {
+                    assert_eq!(x, i);
+                    i += 1;
+                }
+                None => thread::yield_now(),
+            }
+        }
+        t.join().unwrap();
+    });
+}
+
+#[test]
+#[cfg(all(feature = "std", not(loom)))]
+fn atomic_vec() {
+    use crate::AtomicStaticVec;
+    use std::{rc::Rc, thread, vec::Vec};
+
+    let v = AtomicStaticVec::<usize, 64>::new();
+    thread::scope(|s| {
+        for t in 0..4 {
+            let v = &v;
+            s.spawn(move || {
+                for i in 0..20 {
+                    let _ = v.push(t * 100 + i);
+                }
+            });
+        }
+        s.spawn(|| {
+            while !v.is_full() {
+                assert!(v.iter().count() <= v.len());
+            }
+        });
+    });
+    assert!(v.is_full());
+    assert_eq!(v.push(0), Err(0));
+    let items = v.into_static_vec().into_iter().collect::<Vec<_>>();
+    for t in 0..4 {
+        let count = items.iter().filter(|x| **x / 100 == t).count();
+        assert!(count <= 20);
+    }
+    assert_eq!(items.len(), 64);
+
+    static LOG: AtomicStaticVec<u32, 4> = AtomicStaticVec::new();
+    thread::scope(|s| {
+        for i in 0..2 {
+            s.spawn(move || LOG.push(i).unwrap());
+        }
+    });
+    assert_eq!(LOG.len(), 2);
+
+    let rc = Rc::new(());
+    let mut v = AtomicStaticVec::<Rc<()>, 2>::new();
+    v.push(rc.clone()).unwrap();
+    assert_eq!(v.get(0), Some(&rc));
+    assert_eq!(v.get(1), None);
+    v.as_mut_slice()[0] = rc.clone();
+    assert_eq!(Rc::strong_count(&rc), 2);
+    std::mem::drop(v);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+#[cfg(loom)]
+fn atomic_vec_loom() {
+    use crate::AtomicStaticVec;
+    use loom::{sync::Arc, thread};
+
+    loom::model(|| {
+        let v = Arc::new(AtomicStaticVec::<u32, 2>::new());
+        let threads = [1, 2].map(|x| {
+            let v = v.clone();
+            thread::spawn(move || v.push(x).unwrap())
+        });
+        for x in v.iter() {
+            assert!(*x == 1 || *x == 2);
+        }
+        for t in threads {
+            t.join().unwrap();
+        }
+        assert_eq!(v.push(3), Err(3));
+        assert_eq!(v.iter().sum::<u32>(), 3);
+    });
+}
+
+#[test]
+fn try_extend() {
+    let mut v = StaticVec::<i32, 4>::from_array([0]);
+    assert!(v.try_extend(1..3).is_ok());
+    let (x, mut rest) = v.try_extend(3..6).unwrap_err();
+    assert_eq!(x, 4);
+    assert_eq!(rest.next(), Some(5));
+    assert_eq!(v, [0, 1, 2, 3]);
+
+    v.truncate(2);
+    assert_eq!(v.try_extend_exact(2..5), Err(crate::error::FullError));
+    assert_eq!(v, [0, 1]);
+    assert_eq!(v.try_extend_exact(2..4), Ok(()));
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+fn collect() {
+    let v: StaticVec<i32, 4> = (0..3).collect();
+    assert_eq!(v, [0, 1, 2]);
+    let mut v = StaticVec::<i32, 4>::try_from_iter(0..4).unwrap();
+    assert!(StaticVec::<i32, 4>::try_from_iter(0..5).is_err());
+
+    v.truncate(1);
+    v.extend(1..4);
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn collect_overflow() {
+    let _: StaticVec<i32, 4> = (0..5).collect();
+}
+
+#[test]
+fn insert() {
+    let mut v = StaticVec::<i32, 4>::from_array([1, 3]);
+    assert_eq!(v.insert(0, 0), Ok(()));
+    assert_eq!(v.insert(2, 2), Ok(()));
+    assert_eq!(v.insert(4, 4), Err(4));
+    assert_eq!(v, [0, 1, 2, 3]);
+
+    let mut s = crate::GenericString::<[u8; 6]>::try_from("ab").unwrap();
+    s.insert(1, 'ф').unwrap();
+    s.insert(0, 'я').unwrap();
+    assert_eq!(s.as_str(), "яaфb");
+    assert_eq!(s.insert(2, 'x'), Err(crate::error::FullError));
+    assert_eq!(s.remove(2), 'a');
+    assert_eq!(s.pop(), Some('b'));
+    assert_eq!(s.as_str(), "яф");
+}
+
+#[test]
+fn overflow_policy() {
+    use crate::policy::{Evicting, Overwriting, Saturating};
+
+    let mut v = Saturating::<StaticVec<i32, 3>>::default();
+    assert_eq!(v.push_slice(&[0, 1, 2, 3, 4]), 2);
+    assert_eq!(v.insert(0, 5), Some(5));
+    assert_eq!(*v, [0, 1, 2]);
+
+    let mut v = Overwriting::<StaticVec<i32, 3>>::default();
+    v.extend(0..5);
+    assert_eq!(*v, [0, 1, 4]);
+    assert_eq!(v.insert(0, 5), Some(4));
+    assert_eq!(*v, [5, 0, 1]);
+
+    let mut v = Evicting::new(StaticVec::<i32, 3>::new());
+    v.extend(0..5);
+    assert_eq!(*v, [2, 3, 4]);
+    assert_eq!(v.insert(1, 5), Some(2));
+    assert_eq!(*v, [5, 3, 4]);
+    assert_eq!(v.insert(0, 6), Some(6));
+    v.as_mut_slice()[0] = 6;
+    assert_eq!(v.swap_remove(0), 6);
+    assert_eq!(v.pop(), Some(3));
+    v.push(7);
+    assert_eq!(v.into_inner(), [4, 7]);
+}
+
+#[test]
+#[should_panic]
+fn overflow_policy_panic() {
+    let mut v = crate::policy::Panicking::new(StaticVec::<i32, 3>::new());
+    v.extend(0..4);
+}
+
+#[test]
+fn overflow_policy_string() {
+    use crate::{
+        policy::{Evicting, Saturating},
+        GenericString,
+    };
+    use core::fmt::Write;
+
+    let mut s = Saturating::<GenericString<[u8; 5]>>::default();
+    assert!(write!(s, "{}", 1234).is_ok());
+    assert!(write!(s, "ф").is_err());
+    assert_eq!(s.as_str(), "1234");
+
+    let mut s = Saturating::new(GenericString::<[u8; 4]>::try_from("ab").unwrap());
+    assert_eq!(s.push_str("€c"), 2);
+    assert_eq!(s.as_str(), "ab");
+    s.extend("ab€cd".chars());
+    assert_eq!(s.as_str(), "abab");
+
+    let mut s = Evicting::<GenericString<[u8; 5]>>::default();
+    write!(s, "{}", 1234).unwrap();
+    assert_eq!(s.push('ф'), 1);
+    assert_eq!(s.push('я'), 2);
+    assert_eq!(s.as_str(), "4фя");
+
+    let mut s = Evicting::new(GenericString::<[u8; 2]>::try_from("ab").unwrap());
+    assert_eq!(s.push('€'), 1);
+    assert_eq!(s.as_str(), "ab");
+    let mut s = crate::policy::Overwriting::new(GenericString::<[u8; 2]>::try_from("ab").unwrap());
+    assert_eq!(s.insert(0, '€'), 1);
+    assert_eq!(s.as_str(), "ab");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io_write() {
+    use crate::policy::Evicting;
+    use std::io::Write;
+
+    let mut v = StaticVec::<u8, 4>::new();
+    assert_eq!(v.write(b"abcdef").unwrap(), 4);
+    assert!(v.write_all(b"g").is_err());
+
+    let mut v = Evicting::new(StaticVec::<u8, 4>::new());
+    v.write_all(b"abcdef").unwrap();
+    assert_eq!(*v, *b"cdef");
+}
+
+#[test]
+fn jagged() {
+    use crate::StaticJagged;
+
+    let mut j = StaticJagged::<i32, 6, 3>::new();
+    assert_eq!(j.capacity(), 6);
+    assert_eq!(j.row_capacity(), 3);
+    j.push_row(&[0, 1, 2]).unwrap();
+    j.push_row(&[]).unwrap();
+    assert!(j.push_row(&[3, 4, 5, 6]).is_err());
+    j.push_row_from_iter(3..6).unwrap();
+    assert!(j.push_row(&[]).is_err());
+    assert_eq!(j.len(), 3);
+    assert_eq!(j.total_len(), 6);
+    assert_eq!(j.row(0), [0, 1, 2]);
+    assert_eq!(j.row(1), []);
+    assert_eq!(j.get_row(3), None);
+    j.row_mut(2)[0] = 6;
+    assert_eq!(j.rows().map(|r| r.len()).sum::<usize>(), 6);
+
+    let mut it = j.pop_row().unwrap();
+    assert_eq!(it.next(), Some(6));
+    core::mem::drop(it);
+    assert_eq!(j.remaining(), 3);
+    assert_eq!(j.pop_row().unwrap().count(), 0);
+    j.push_row(&[7]).unwrap();
+    assert_eq!(j.as_flat_slice(), [0, 1, 2, 7]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn jagged_drop() {
+    use crate::StaticJagged;
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let mut j = StaticJagged::<Rc<()>, 4, 2>::new();
+    j.push_row(&[rc.clone(), rc.clone()]).unwrap();
+    j.push_row(&[rc.clone(), rc.clone()]).unwrap();
+    assert_eq!(Rc::strong_count(&rc), 5);
+    j.pop_row().unwrap().next().unwrap();
+    assert_eq!(Rc::strong_count(&rc), 3);
+    std::mem::drop(j);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn bit_vec() {
+    use crate::{GenericBitVec, StaticBitVec};
+    use std::{format, vec::Vec};
+
+    let mut a = StaticBitVec::<2>::new();
+    assert_eq!(a.capacity(), 16);
+    for i in 0..10 {
+        a.push(i % 3 == 0).unwrap();
+    }
+    assert_eq!(format!("{:?}", a), "1001001001");
+    assert_eq!(a.count_ones(), 4);
+    assert_eq!(a.iter_ones().collect::<Vec<_>>(), [0, 3, 6, 9]);
+    assert_eq!(a.pop(), Some(true));
+    assert_eq!(a.get(9), None);
+    a.set(1, true);
+    assert_eq!(a.count_zeros(), 5);
+
+    let mut b = GenericBitVec::<[u32; 1], u8>::new();
+    let mut c = StaticBitVec::<2>::new();
+    assert_eq!(b.capacity(), 32);
+    assert_eq!(c.capacity(), 16);
+    assert_eq!(StaticBitVec::<8>::new().capacity(), 64);
+    for i in 0..12 {
+        b.push(i % 2 == 0).unwrap();
+        c.push(i % 2 == 0).unwrap();
+    }
+    assert_eq!(b, c);
+    assert_eq!(b.words(), [0b0101_0101_0101]);
+    assert_eq!(format!("{:?}", &a & &c), "100000100000");
+    assert_eq!(format!("{:?}", &a | &c), "111110101010");
+    a ^= &c;
+    assert_eq!(format!("{:?}", a), "011110001010");
+    assert_eq!(a.len(), 12);
+
+    while a.push(true).is_ok() {}
+    assert!(a.is_full());
+    a.truncate(3);
+    assert_eq!(a.words(), [0b110, 0]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn arena() {
+    use crate::StaticArena;
+    use std::{rc::Rc, string::String};
+
+    let counter = Rc::new(());
+    let arena = StaticArena::<256>::new();
+    {
+        let a = arena.alloc(1u8);
+        let b = arena.alloc(0x1234_5678u32);
+        let c = arena.alloc(String::from("abc"));
+        let d = arena.alloc(counter.clone());
+        assert_eq!((b as *mut u32).align_offset(4), 0);
+        *a += 1;
+        c.push('d');
+        assert_eq!((*a, *b, c.as_str()), (2, 0x1234_5678, "abcd"));
+        assert_eq!(Rc::strong_count(d), 2);
+    }
+    assert!(arena.try_alloc([0u8; 256]).is_err());
+    let len = arena.len();
+    assert!(arena.try_alloc(counter.clone()).is_ok());
+    assert_eq!(Rc::strong_count(&counter), 3);
+    assert!(arena.len() > len);
+
+    let moved = arena;
+    assert_eq!(Rc::strong_count(&counter), 3);
+    let mut arena = moved;
+    arena.reset();
+    assert!(arena.is_empty());
+    assert_eq!(Rc::strong_count(&counter), 1);
+
+    arena.alloc(counter.clone());
+    core::mem::drop(arena);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "allocator-api2"))]
+fn arena_allocator() {
+    use crate::StaticArena;
+    use allocator_api2::{boxed::Box, vec::Vec};
+
+    let arena = StaticArena::<128>::new();
+    let mut vec = Vec::new_in(&arena);
+    for i in 0..16u32 {
+        vec.push(i);
+    }
+    assert_eq!(vec.iter().sum::<u32>(), 120);
+    let len = arena.len();
+    assert!(len >= 64);
+    core::mem::drop(vec);
+    assert!(arena.len() < len);
+
+    let boxed = Box::new_in(7u64, &arena);
+    assert_eq!(*boxed, 7);
+    assert!(Box::try_new_in([0u8; 128], &arena).is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn slab() {
+    use crate::StaticSlab;
+    use std::{rc::Rc, vec::Vec};
+
+    let counter = Rc::new(());
+    let mut slab = StaticSlab::<_, 3>::new();
+    let a = slab.insert((0, counter.clone())).unwrap();
+    let b = slab.insert((1, counter.clone())).unwrap();
+    let c = slab.insert((2, counter.clone())).unwrap();
+    assert!(slab.is_full());
+    assert_eq!(slab.insert((3, counter.clone())).unwrap_err().0, 3);
+
+    assert_eq!(slab.remove(b).unwrap().0, 1);
+    assert!(!slab.contains(b));
+    assert!(slab.get(b).is_none());
+    assert!(slab.remove(b).is_none());
+    assert_eq!(Rc::strong_count(&counter), 3);
+
+    let d = slab.insert((4, counter.clone())).unwrap();
+    assert_eq!(d.index(), b.index());
+    assert_ne!(d, b);
+    assert!(slab.get(b).is_none());
+    slab.get_mut(d).unwrap().0 += 1;
+    assert_eq!(slab.get(d).unwrap().0, 5);
+
+    for (_, (x, _)) in slab.iter_mut() {
+        *x *= 10;
+    }
+    assert_eq!(
+        slab.iter().map(|(k, (x, _))| (k, *x)).collect::<Vec<_>>(),
+        [(a, 0), (d, 50), (c, 20)]
+    );
+
+    slab.remove(a);
+    slab.remove(c);
+    assert_eq!(slab.len(), 1);
+    let e = slab.insert((6, counter.clone())).unwrap();
+    assert_eq!(e.index(), c.index());
+    assert_eq!(Rc::strong_count(&counter), 3);
+    slab.clear();
+    assert!(slab.is_empty());
+    assert_eq!(Rc::strong_count(&counter), 1);
+
+    slab.insert((7, counter.clone())).unwrap();
+    core::mem::drop(slab);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn slab_generic() {
+    use crate::{GenericSlab, SlabEntry};
+    use core::mem::MaybeUninit;
+
+    let mut slab = GenericSlab::<[u32; 2], [SlabEntry; 2]>::new();
+    let a = slab.insert(1).unwrap();
+    slab.insert(2).unwrap();
+    assert_eq!(slab.insert(3), Err(3));
+    assert_eq!(slab.remove(a), Some(1));
+
+    let mut data = [MaybeUninit::<i32>::uninit(); 4];
+    let mut entries = [SlabEntry::default(); 4];
+    let mut slab = GenericSlab::from_empty(&mut data[..], &mut entries[..]);
+    assert_eq!(slab.capacity(), 4);
+    let b = slab.insert(5).unwrap();
+    assert_eq!(slab.get(b), Some(&5));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn interner() {
+    use crate::{error::FullError, StaticInterner};
+    use std::{format, vec::Vec};
+
+    let mut interner = StaticInterner::<10, 3>::new();
+    let a = interner.intern("abc").unwrap();
+    let b = interner.intern("de").unwrap();
+    assert_eq!(interner.intern("abc"), Ok(a));
+    assert_ne!(a, b);
+    assert_eq!(interner.get("de"), Some(b));
+    assert_eq!(interner.get("d"), None);
+    assert_eq!(interner.resolve(a), Some("abc"));
+    assert_eq!(interner.resolve(b), Some("de"));
+
+    assert_eq!(interner.intern("fghijk"), Err(FullError));
+    let c = interner.intern("").unwrap();
+    assert_eq!(interner.resolve(c), Some(""));
+    assert_eq!(interner.intern("f"), Err(FullError));
+    assert_eq!(
+        interner.iter().map(|(s, _)| s.index()).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+    assert_eq!(format!("{:?}", interner), r#"["abc", "de", ""]"#);
+
+    interner.clear();
+    assert!(interner.is_empty());
+    assert_eq!(interner.resolve(a), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn ascii_string() {
+    use crate::{error::AsciiError, GenericString, StaticAsciiString};
+    use core::cmp::Ordering;
+    use std::format;
+
+    let mut s = StaticAsciiString::<8>::try_from("Call").unwrap();
+    assert_eq!(
+        StaticAsciiString::<8>::try_from("Привет"),
+        Err(AsciiError::NotAscii(0))
+    );
+    s.push(b'-').unwrap();
+    assert_eq!(s.push(0xc0), Err(AsciiError::NotAscii(0)));
+    assert_eq!(s.push_str("sign42"), Err(AsciiError::Full));
+    assert_eq!(s.push_str("a\u{e9}"), Err(AsciiError::NotAscii(1)));
+    s.push_str("X1").unwrap();
+    assert_eq!(s, "Call-X1");
+    assert_eq!(s[5], b'X');
+    assert_eq!(s.get(7), None);
+
+    assert!(s.eq_ignore_ascii_case("CALL-x1"));
+    assert_eq!(s.cmp_ignore_ascii_case("call-x2"), Ordering::Less);
+    assert_eq!(s.cmp_ignore_ascii_case("CALL"), Ordering::Greater);
+    s.make_ascii_uppercase();
+    assert_eq!(s.as_str(), "CALL-X1");
+    s.set(4, b'_').unwrap();
+    assert_eq!(format!("{}", s), "CALL_X1");
+
+    let string: GenericString<[u8; 8]> = s.into();
+    assert_eq!(string.as_str(), "CALL_X1");
+    let s = StaticAsciiString::try_from(string).unwrap();
+    assert_eq!(s.len(), 7);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn c_string() {
+    use crate::{error::CStringError, GenericString, StaticCString};
+    use core::ffi::CStr;
+    use std::format;
+
+    let mut s = StaticCString::<8>::try_from("abc").unwrap();
+    assert_eq!(s.capacity(), 7);
+    assert_eq!(s.as_bytes_with_nul(), b"abc\0");
+    assert_eq!(
+        StaticCString::<8>::try_from("a\0b"),
+        Err(CStringError::InteriorNul(1))
+    );
+    assert_eq!(s.push_str("defgh"), Err(CStringError::Full));
+    assert_eq!(s.push(0), Err(CStringError::InteriorNul(0)));
+    s.push_bytes(b"\xffdef").unwrap();
+    assert!(s.is_full());
+    assert_eq!(s.push(b'x'), Err(CStringError::Full));
+    assert_eq!(s.as_bytes(), b"abc\xffdef");
+    assert!(s.to_str().is_err());
+    assert_eq!(format!("{:?}", s), r#""abc\xffdef""#);
+    assert_eq!(unsafe { CStr::from_ptr(s.as_ptr()) }, s.as_c_str());
+
+    assert_eq!(s.pop(), Some(b'f'));
+    s.truncate(3);
+    assert_eq!(s, c"abc");
+    assert!(s.clone().into_string().is_ok());
+
+    let c = StaticCString::<4>::try_from(c"xyz").unwrap();
+    assert!(StaticCString::<3>::try_from(c"xyz").is_err());
+    let string = GenericString::try_from(c).unwrap();
+    assert_eq!(string.as_str(), "xyz");
+    assert_eq!(StaticCString::try_from(string).unwrap(), c"xyz");
+    let string = GenericString::<[u8; 4]>::try_from("abcd").unwrap();
+    let err = StaticCString::try_from(string).unwrap_err();
+    assert_eq!(err.error(), CStringError::Full);
+    assert_eq!(err.into_inner().as_str(), "abcd");
+    let string = GenericString::<[u8; 4]>::try_from("a\0b").unwrap();
+    let err = StaticCString::try_from(string).unwrap_err();
+    assert_eq!(err.error(), CStringError::InteriorNul(1));
+    assert_eq!(err.into_inner().as_str(), "a\0b");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn wide_string() {
+    use crate::{error::FullError, error::WideStringError, GenericString, StaticWideString};
+    use std::format;
+
+    let mut s: StaticWideString<7> = "a\u{e9}\u{1f600}".parse().unwrap();
+    assert_eq!(s.len(), 4);
+    assert_eq!(s.as_units(), [0x61, 0xe9, 0xd83d, 0xde00]);
+    assert_eq!(s.push('\u{10000}'), Ok(()));
+    assert_eq!(s.push('\u{10001}'), Err(FullError));
+    assert_eq!(s.push_str("bc"), Err(FullError));
+    s.push('\'').unwrap();
+    assert!(s.is_full());
+    assert_eq!(s, "a\u{e9}\u{1f600}\u{10000}'");
+    assert_eq!(format!("{}", s), "a\u{e9}\u{1f600}\u{10000}'");
+    assert_eq!(
+        format!("{:?}", s),
+        format!("{:?}", "a\u{e9}\u{1f600}\u{10000}'")
+    );
+
+    assert_eq!(s.pop(), Some('\''));
+    assert_eq!(s.pop(), Some('\u{10000}'));
+    let string: GenericString<[u8; 7]> = s.try_to_string().unwrap();
+    assert_eq!(string.as_str(), "a\u{e9}\u{1f600}");
+    assert!(s.try_to_string::<[u8; 6], usize>().is_err());
+
+    assert_eq!(
+        StaticWideString::<4>::try_from_units(&[0x61, 0xd83d, 0x62]),
+        Err(WideStringError::UnpairedSurrogate(1))
+    );
+    assert_eq!(
+        StaticWideString::<4>::try_from_units(&[0xde00]),
+        Err(WideStringError::UnpairedSurrogate(0))
+    );
+    assert_eq!(
+        StaticWideString::<1>::try_from_units(&[0x61, 0x62]),
+        Err(WideStringError::Full)
+    );
+}
+
+#[test]
+fn utf8_lossy() {
+    use crate::{error::FullError, GenericString, Utf8Decoder};
+
+    type S = GenericString<[u8; 16]>;
+    let s = S::from_utf8_lossy(b"ab\xffc\xe2\x82").unwrap();
+    assert_eq!(s.as_str(), "ab\u{fffd}c\u{fffd}");
+    assert_eq!(
+        GenericString::<[u8; 5]>::from_utf8_lossy(b"ab\xffc"),
+        Err(FullError)
+    );
+
+    let input = "a\u{20ac}b\u{1f600}".as_bytes();
+    for split in 0..input.len() {
+        let mut out = S::default();
+        let mut decoder = Utf8Decoder::new();
+        assert_eq!(decoder.decode(&input[..split], &mut out), split);
+        assert_eq!(
+            decoder.decode(&input[split..], &mut out),
+            input.len() - split
+        );
+        decoder.finish(&mut out).unwrap();
+        assert_eq!(out.as_str(), "a\u{20ac}b\u{1f600}");
+    }
+
+    let mut out = S::default();
+    let mut decoder = Utf8Decoder::new();
+    for byte in b"\xe2\x82A\xf0\x9f\x98" {
+        assert_eq!(decoder.decode(&[*byte], &mut out), 1);
+    }
+    assert_eq!(decoder.pending(), b"\xf0\x9f\x98");
+    decoder.finish(&mut out).unwrap();
+    assert_eq!(out.as_str(), "\u{fffd}A\u{fffd}");
+
+    let mut out = GenericString::<[u8; 4]>::default();
+    let mut decoder = Utf8Decoder::new();
+    assert_eq!(decoder.decode(b"ab\xe2\x82", &mut out), 4);
+    assert_eq!(decoder.decode(b"\xacc", &mut out), 0);
+    out.clear();
+    assert_eq!(decoder.decode(b"\xacc", &mut out), 2);
+    assert_eq!(decoder.decode(b"\x80de", &mut out), 0);
+    assert_eq!(out.as_str(), "\u{20ac}c");
+}
+
+#[test]
+fn string_truncated() {
+    use crate::GenericString;
+
+    type S = GenericString<[u8; 6]>;
+    let (s, dropped) = S::from_str_truncated("ab\u{20ac}cd");
+    assert_eq!((s.as_str(), dropped), ("ab\u{20ac}c", 1));
+    let (s, dropped) = S::from_str_truncated("abcd\u{20ac}");
+    assert_eq!((s.as_str(), dropped), ("abcd", 3));
+    let (s, dropped) = S::from_str_truncated("abc");
+    assert_eq!((s.as_str(), dropped), ("abc", 0));
+
+    let (s, dropped) = S::from_str_truncated_with("abcdef", "...");
+    assert_eq!((s.as_str(), dropped), ("abcdef", 0));
+    let (s, dropped) = S::from_str_truncated_with("abcdefg", "...");
+    assert_eq!((s.as_str(), dropped), ("abc...", 4));
+    let (s, dropped) = S::from_str_truncated_with("ab\u{20ac}cd", "\u{2026}");
+    assert_eq!((s.as_str(), dropped), ("ab\u{2026}", 5));
+
+    let mut s = S::try_from("abcde").unwrap();
+    assert_eq!(s.push_truncated('\u{e9}'), 2);
+    assert_eq!(s.push_truncated('f'), 0);
+    assert_eq!(s.push_str_truncated("g"), 1);
+    assert_eq!(s.push_str_truncated_with("h", "..."), 1);
+    assert_eq!(s.as_str(), "abcdef");
+    s.clear();
+    assert_eq!(s.push_str_truncated_with("abcdefgh", "[truncated]"), 8);
+    assert_eq!(s.as_str(), "[trunc");
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn string_truncated_unicode() {
+    use crate::GenericString;
+
+    type S = GenericString<[u8; 8]>;
+    // `e` with combining acute accent.
+    let (s, dropped) = S::from_str_truncated_graphemes("abcdef\u{301}g");
+    assert_eq!((s.as_str(), dropped), ("abcdef\u{301}", 1));
+    let (s, dropped) = S::from_str_truncated_graphemes("abcdefg\u{301}");
+    assert_eq!((s.as_str(), dropped), ("abcdef", 3));
+    // Family emoji is a single grapheme of 18 bytes.
+    let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+    let (s, dropped) = GenericString::<[u8; 20]>::from_str_truncated_graphemes(family);
+    assert_eq!((s.as_str(), dropped), (family, 0));
+    let (s, dropped) = GenericString::<[u8; 17]>::from_str_truncated_graphemes(family);
+    assert_eq!((s.as_str(), dropped), ("", 18));
+
+    type W = GenericString<[u8; 32]>;
+    let (s, dropped) = W::from_str_truncated_width("\u{4f60}\u{597d}\u{4e16}\u{754c}", 5);
+    assert_eq!((s.as_str(), dropped), ("\u{4f60}\u{597d}", 6));
+    assert_eq!(s.width(), 4);
+    let (s, _) = W::from_str_truncated_width("a\u{1f600}b", 2);
+    assert_eq!(s.as_str(), "a");
+
+    let mut s = W::default();
+    assert_eq!(
+        s.push_str_truncated_width_with("Hello, world", 8, "\u{2026}"),
+        5
+    );
+    assert_eq!(s.as_str(), "Hello, \u{2026}");
+    assert_eq!(s.width(), 8);
+    s.clear();
+    assert_eq!(s.push_str_truncated_width_with("Hello", 8, "\u{2026}"), 0);
+    assert_eq!(s.as_str(), "Hello");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn cross_type_cmp() {
+    use crate::{GenericString, GenericVec};
+    use core::mem::MaybeUninit;
+    use std::{collections::HashMap, string::String};
+
+    let a = StaticVec::<i32, 4>::from_iter([1, 2, 3]);
+    let b = GenericVec::<[i32; 8], u8>::from_iter([1, 2, 3]);
+    let c = StaticVec::<i32, 2>::from_iter([1, 3]);
+    assert_eq!(a, b);
+    assert_ne!(b, c);
+    assert!(a < c);
+    assert!(c > b);
+
+    type S4 = GenericString<[MaybeUninit<u8>; 4]>;
+    type S8 = GenericString<[u8; 8], u16>;
+    let x = S4::try_from("abc").unwrap();
+    let y = S8::try_from("abc").unwrap();
+    let z = S8::try_from("abd").unwrap();
+    assert_eq!(x, y);
+    assert!(x < z);
+    assert_eq!(x, "abc");
+    assert_eq!("abc", x);
+    assert_eq!(*"abc", x);
+    assert_eq!(String::from("abc"), x);
+    assert_eq!(x, String::from("abc"));
+    assert!(z > "abc");
+    assert!("abb" < z);
+
+    let mut map = HashMap::new();
+    map.insert(x, 1);
+    map.insert(S4::try_from("de").unwrap(), 2);
+    assert_eq!(map.get("abc"), Some(&1));
+    assert_eq!(map.get("de"), Some(&2));
+    assert_eq!(map.get(y.as_str()), Some(&1));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn change_capacity() {
+    use crate::{GenericString, GenericVec};
+    use std::{rc::Rc, vec::Vec};
+
+    let counter = Rc::new(());
+    let vec = StaticVec::<_, 4>::from_iter((0..3).map(|i| (i, counter.clone())));
+    let vec = vec.resize_capacity::<8>();
+    assert_eq!(vec.capacity(), 8);
+    assert_eq!(Rc::strong_count(&counter), 4);
+    let vec = vec.try_into_capacity::<2>().unwrap_err();
+    let vec = vec.try_into_capacity::<3>().unwrap();
+    assert_eq!(vec.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(Rc::strong_count(&counter), 4);
+
+    let vec: GenericVec<[core::mem::MaybeUninit<_>; 4], u8> = vec.try_convert().unwrap();
+    assert_eq!(vec.len(), 3);
+    core::mem::drop(vec);
+    assert_eq!(Rc::strong_count(&counter), 1);
+
+    let big = StaticVec::<u8, 300>::from_iter(0..255).resize_capacity::<400>();
+    assert!(big.try_convert::<[u8; 400], u8>().is_ok());
+    let big = StaticVec::<u8, 300>::from_iter((0..=255).chain(0..1));
+    assert!(big.try_convert::<[u8; 400], u8>().is_err());
+
+    let s = GenericString::<[u8; 4]>::try_from("abc").unwrap();
+    let s = s.resize_capacity::<8>();
+    assert_eq!(s.capacity(), 8);
+    let s = s.try_into_capacity::<2>().unwrap_err();
+    let s: GenericString<[u8; 3], u8> = s.try_convert().unwrap();
+    assert_eq!(s, "abc");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn array_chunks() {
+    use std::rc::Rc;
+
+    let mut v = StaticVec::<i32, 8>::from_array([1, 2, 3, 4, 5]);
+    let (chunks, rem) = v.as_chunks::<2>();
+    assert_eq!(chunks, [[1, 2], [3, 4]]);
+    assert_eq!(rem, [5]);
+    let (chunks, rem) = v.as_chunks_mut::<3>();
+    chunks[0].reverse();
+    rem[1] = 0;
+    assert_eq!(v, [3, 2, 1, 4, 0]);
+
+    assert_eq!(v.push_array([6, 7]), Ok(()));
+    assert_eq!(v.push_array([8, 9]), Err([8, 9]));
+    assert_eq!(v.len(), 7);
+
+    let v = v.try_into_array::<6>().unwrap_err().into_inner();
+    assert_eq!(v.try_into_array::<7>().unwrap(), [3, 2, 1, 4, 0, 6, 7]);
+
+    let v = StaticVec::<i32, 4>::try_from_chunks([[1, 2], [3, 4]]).unwrap();
+    assert_eq!(<[i32; 4]>::try_from(v).unwrap(), [1, 2, 3, 4]);
+    assert!(StaticVec::<i32, 4>::try_from_chunks([[1, 2], [3, 4], [5, 6]]).is_err());
+
+    let counter = Rc::new(());
+    let v = StaticVec::<_, 4>::from_array([counter.clone()]);
+    let array = v.into_array_padded(counter.clone());
+    assert_eq!(Rc::strong_count(&counter), 5);
+    core::mem::drop(array);
+    let v = StaticVec::<_, 4>::from_array([counter.clone()]);
+    core::mem::drop(<[_; 4]>::try_from(v).unwrap_err().into_inner());
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn spare_capacity() {
+    use core::mem::MaybeUninit;
+
+    let mut v = StaticVec::<i32, 4>::from_array([1]);
+    let spare = v.spare_capacity_mut();
+    assert_eq!(spare.len(), 3);
+    spare[0] = MaybeUninit::new(2);
+    unsafe { v.set_len(2) };
+    assert_eq!(v, [1, 2]);
+
+    let mut v = crate::GenericVec::<[u32; 4]>::new();
+    v.push(1).unwrap();
+    let count = v.fill_spare(|buf| {
+        buf[..2].copy_from_slice(&[2, 3]);
+        2
+    });
+    assert_eq!(count, 2);
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v.fill_spare(|buf| buf.len()), 1);
+    assert!(v.is_full());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn spare_capacity_read() {
+    use crate::GenericVec;
+
+    let mut reader: &[u8] = b"hello world";
+    let mut v = StaticVec::<u8, 8>::new();
+    assert_eq!(v.read_into(&mut reader).unwrap(), 8);
+    assert_eq!(v, b"hello wo".as_slice());
+    assert_eq!(v.read_into(&mut reader).unwrap(), 0);
+
+    let mut v = GenericVec::<[u8; 16]>::new();
+    assert_eq!(v.read_into(&mut reader).unwrap(), 3);
+    assert_eq!(v, b"rld".as_slice());
+}
@@ -0,0 +1,401 @@
+//! Checks that every item is dropped exactly once, including the cases when `clone` or `drop` panics.
+//!
+//! Tests don't touch files or threads, so they could be run under Miri with `cargo miri test --lib soundness`.
+
+use crate::{
+    AtomicStaticVec, GenericVec, SliceVec, StaticArena, StaticJagged, StaticSlab, StaticSpsc,
+    StaticVec,
+};
+use core::{cell::Cell, mem::MaybeUninit};
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    vec,
+    vec::Vec,
+};
+
+/// Shared state of tracked items.
+#[derive(Default, Debug)]
+struct Tracker {
+    live: Cell<usize>,
+    /// Number of successful clones left before `clone` panics.
+    clones_left: Cell<Option<usize>>,
+}
+
+impl Tracker {
+    fn new() -> Self {
+        Self::default()
+    }
+    fn item(&self, value: i32) -> Item<'_> {
+        self.live.set(self.live.get() + 1);
+        Item {
+            tracker: self,
+            value,
+            panic_on_drop: false,
+        }
+    }
+    /// Item which panics when dropped.
+    fn bomb(&self, value: i32) -> Item<'_> {
+        let mut item = self.item(value);
+        item.panic_on_drop = true;
+        item
+    }
+    fn live(&self) -> usize {
+        self.live.get()
+    }
+    fn panic_after_clones(&self, count: usize) {
+        self.clones_left.set(Some(count));
+    }
+}
+
+#[derive(Debug)]
+struct Item<'a> {
+    tracker: &'a Tracker,
+    value: i32,
+    panic_on_drop: bool,
+}
+
+impl Clone for Item<'_> {
+    fn clone(&self) -> Self {
+        if let Some(count) = self.tracker.clones_left.get() {
+            if count == 0 {
+                panic!("clone");
+            }
+            self.tracker.clones_left.set(Some(count - 1));
+        }
+        self.tracker.item(self.value)
+    }
+}
+
+impl Drop for Item<'_> {
+    fn drop(&mut self) {
+        let live = self.tracker.live.get();
+        assert!(live > 0, "double drop");
+        self.tracker.live.set(live - 1);
+        if self.panic_on_drop {
+            panic!("drop");
+        }
+    }
+}
+
+fn values(items: &[Item]) -> Vec<i32> {
+    items.iter().map(|x| x.value).collect()
+}
+
+#[test]
+fn mutators() {
+    let t = Tracker::new();
+    let mut v = StaticVec::<Item, 6>::new();
+    for i in 0..4 {
+        v.push(t.item(i)).unwrap();
+    }
+    assert_eq!(v.push(t.item(4)).map_err(|x| x.value), Ok(()));
+    assert_eq!(v.push(t.item(5)).map_err(|x| x.value), Ok(()));
+    assert_eq!(v.push(t.item(6)).map_err(|x| x.value), Err(6));
+    assert_eq!(t.live(), 6);
+
+    assert_eq!(v.pop().unwrap().value, 5);
+    assert_eq!(v.remove(0).value, 0);
+    assert_eq!(v.swap_remove(0).value, 1);
+    assert_eq!(v.insert(1, t.item(7)).map_err(|x| x.value), Ok(()));
+    assert_eq!(values(&v), [4, 7, 2, 3]);
+    assert_eq!(t.live(), 4);
+
+    v.truncate(2);
+    assert_eq!(t.live(), 2);
+    v.resize(4, t.item(8));
+    assert_eq!(values(&v), [4, 7, 8, 8]);
+    assert_eq!(t.live(), 4);
+    v.resize(1, t.item(9));
+    assert_eq!(t.live(), 1);
+
+    assert!(v.try_extend_exact((0..6).map(|i| t.item(i))).is_err());
+    assert_eq!(t.live(), 1);
+    v.extend_until_full((0..6).map(|i| t.item(i)));
+    assert_eq!(t.live(), 6);
+    v.clear();
+    assert_eq!(t.live(), 0);
+
+    v.push_array([t.item(0), t.item(1)]).unwrap();
+    assert!(v.push_slice(&[t.item(2), t.item(3)]).is_ok());
+    let cloned = v.clone();
+    assert_eq!(t.live(), 8);
+    drop(cloned);
+    drop(v);
+    assert_eq!(t.live(), 0);
+}
+
+#[test]
+fn conversions() {
+    let t = Tracker::new();
+    let v = StaticVec::<_, 4>::from_array([t.item(0), t.item(1)]);
+    let (data, len) = unsafe { v.into_raw_parts() };
+    let v = unsafe { StaticVec::from_raw_parts(data, len) };
+    let v = v.resize_capacity::<8>();
+    let v = v.try_into_capacity::<1>().unwrap_err();
+    let v: GenericVec<[MaybeUninit<_>; 2], u8> = v.try_convert().unwrap();
+    let v: StaticVec<_, 2> = v.try_convert().unwrap();
+    let array: [Item; 2] = v.try_into().unwrap();
+    assert_eq!(t.live(), 2);
+    drop(array);
+
+    let v = StaticVec::<_, 4>::from_array([t.item(0)]);
+    let v = v.try_into_array::<2>().unwrap_err().into_inner();
+    let array = v.into_array_padded(t.item(1));
+    assert_eq!(t.live(), 4);
+    drop(array);
+    assert_eq!(t.live(), 0);
+
+    let v = StaticVec::<_, 4>::from_array([t.item(0), t.item(1), t.item(2)]);
+    let mut iter = v.into_iter();
+    assert_eq!(iter.next().unwrap().value, 0);
+    assert_eq!(t.live(), 2);
+    drop(iter);
+    assert_eq!(t.live(), 0);
+
+    let v = AtomicStaticVec::<_, 4>::new();
+    v.push(t.item(0)).unwrap();
+    v.push(t.item(1)).unwrap();
+    let mut v = v.into_static_vec();
+    v.push(t.item(2)).unwrap();
+    drop(v);
+    assert_eq!(t.live(), 0);
+}
+
+#[test]
+fn zero_sized() {
+    let mut v = StaticVec::<(), 4>::new();
+    v.push(()).unwrap();
+    v.push_slice(&[(), ()]).unwrap();
+    assert_eq!(v.remove(0), ());
+    assert_eq!(v.swap_remove(0), ());
+    assert_eq!(v.as_chunks::<1>().0.len(), 1);
+    assert_eq!(v.into_iter().count(), 1);
+}
+
+#[test]
+fn panic_in_clone() {
+    let t = Tracker::new();
+
+    let mut v = StaticVec::<_, 8>::from_array([t.item(0)]);
+    let slice = [t.item(1), t.item(2), t.item(3)];
+    t.panic_after_clones(2);
+    assert!(catch_unwind(AssertUnwindSafe(|| v.push_slice(&slice))).is_err());
+    assert_eq!(values(&v), [0, 1, 2]);
+    assert_eq!(t.live(), 6);
+
+    t.panic_after_clones(1);
+    let value = t.item(4);
+    assert!(catch_unwind(AssertUnwindSafe(|| v.resize(6, value))).is_err());
+    assert_eq!(values(&v), [0, 1, 2, 4]);
+    assert_eq!(t.live(), 7);
+
+    t.panic_after_clones(2);
+    assert!(catch_unwind(AssertUnwindSafe(|| v.clone())).is_err());
+    assert_eq!(t.live(), 7);
+
+    t.panic_after_clones(0);
+    let fill = t.item(5);
+    assert!(catch_unwind(AssertUnwindSafe(|| v.into_array_padded(fill))).is_err());
+    assert_eq!(t.live(), 3);
+
+    t.panic_after_clones(1);
+    let from_slice = || StaticVec::<_, 4>::try_from_slice(&slice);
+    assert!(catch_unwind(AssertUnwindSafe(from_slice)).is_err());
+    assert_eq!(t.live(), 3);
+    drop(slice);
+    assert_eq!(t.live(), 0);
+}
+
+#[test]
+fn panic_in_drop() {
+    let t = Tracker::new();
+
+    let v = StaticVec::<_, 4>::from_array([t.item(0), t.bomb(1), t.item(2)]);
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(v))).is_err());
+    assert_eq!(t.live(), 0);
+
+    let mut v = StaticVec::<_, 4>::from_array([t.item(0), t.item(1), t.bomb(2), t.item(3)]);
+    assert!(catch_unwind(AssertUnwindSafe(|| v.truncate(1))).is_err());
+    assert_eq!(values(&v), [0, 1]);
+    assert_eq!(t.live(), 2);
+    v.truncate(0);
+    assert_eq!(t.live(), 0);
+
+    let v = StaticVec::<_, 4>::from_array([t.item(0), t.bomb(1), t.item(2), t.item(3)]);
+    let mut iter = v.into_iter();
+    iter.next();
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(iter))).is_err());
+    assert_eq!(t.live(), 0);
+
+    let v = AtomicStaticVec::<_, 4>::new();
+    v.push(t.bomb(0)).unwrap();
+    v.push(t.item(1)).unwrap();
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(v))).is_err());
+    assert_eq!(t.live(), 0);
+}
+
+#[test]
+fn panic_in_iterator() {
+    let t = Tracker::new();
+    let iter = (0..4).map(|i| if i < 2 { t.item(i) } else { panic!("next") });
+    assert!(catch_unwind(AssertUnwindSafe(|| StaticVec::<_, 4>::from_iter(iter))).is_err());
+    assert_eq!(t.live(), 0);
+
+    let mut v = StaticVec::<_, 4>::new();
+    let iter = (0..4).map(|i| if i < 3 { t.item(i) } else { panic!("next") });
+    assert!(catch_unwind(AssertUnwindSafe(|| v.try_extend_exact(iter))).is_err());
+    assert_eq!(values(&v), [0, 1, 2]);
+    drop(v);
+    assert_eq!(t.live(), 0);
+}
+
+#[test]
+fn containers() {
+    let t = Tracker::new();
+
+    let mut slab = StaticSlab::<_, 4>::new();
+    let a = slab.insert(t.item(0)).unwrap();
+    slab.insert(t.item(1)).unwrap();
+    assert_eq!(slab.remove(a).unwrap().value, 0);
+    slab.insert(t.item(2)).unwrap();
+    assert_eq!(t.live(), 2);
+    slab.clear();
+    assert_eq!(t.live(), 0);
+    slab.insert(t.item(3)).unwrap();
+    drop(slab);
+    assert_eq!(t.live(), 0);
+
+    let mut arena = StaticArena::<256>::new();
+    arena.alloc(t.item(0));
+    assert_eq!(arena.alloc(t.item(1)).value, 1);
+    assert_eq!(t.live(), 2);
+    arena.reset();
+    assert_eq!(t.live(), 0);
+    arena.alloc(t.item(2));
+    drop(arena);
+    assert_eq!(t.live(), 0);
+
+    let mut queue = StaticSpsc::<_, 3>::new();
+    {
+        let (mut producer, mut consumer) = queue.split();
+        for i in 0..3 {
+            producer.push(t.item(i)).unwrap();
+        }
+        assert_eq!(consumer.pop().unwrap().value, 0);
+        assert_eq!(consumer.pop().unwrap().value, 1);
+        producer.push_slice(&[t.item(3), t.item(4)]);
+    }
+    assert_eq!(t.live(), 3);
+    drop(queue);
+    assert_eq!(t.live(), 0);
+
+    let mut jagged = StaticJagged::<_, 6, 3>::new();
+    jagged.push_row_from_iter([t.item(0), t.item(1)]).unwrap();
+    jagged
+        .push_row_from_iter([t.item(2), t.item(3), t.item(4)])
+        .unwrap();
+    let mut row = jagged.pop_row().unwrap();
+    assert_eq!(row.next().unwrap().value, 2);
+    drop(row);
+    assert_eq!(t.live(), 2);
+    drop(jagged);
+    assert_eq!(t.live(), 0);
+
+    let mut buf: [MaybeUninit<Item>; 4] = core::array::from_fn(|_| MaybeUninit::uninit());
+    let mut v = SliceVec::from_uninit_slice(&mut buf);
+    v.push_array([t.item(0), t.item(1), t.item(2)]).unwrap();
+    v.into_uninit_slice();
+    assert_eq!(t.live(), 0);
+    let mut v = SliceVec::from_uninit_slice(&mut buf);
+    v.push(t.item(3)).unwrap();
+    drop(v);
+    assert_eq!(t.live(), 0);
+}
+
+#[test]
+fn containers_panic_in_drop() {
+    let t = Tracker::new();
+
+    let mut slab = StaticSlab::<_, 4>::new();
+    slab.insert(t.item(0)).unwrap();
+    slab.insert(t.bomb(1)).unwrap();
+    slab.insert(t.item(2)).unwrap();
+    assert!(catch_unwind(AssertUnwindSafe(|| slab.clear())).is_err());
+    assert!(slab.is_empty());
+    assert_eq!(t.live(), 0);
+    slab.insert(t.bomb(3)).unwrap();
+    slab.insert(t.item(4)).unwrap();
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(slab))).is_err());
+    assert_eq!(t.live(), 0);
+
+    let mut arena = StaticArena::<256>::new();
+    arena.alloc(t.item(0));
+    arena.alloc(t.bomb(1));
+    arena.alloc(t.item(2));
+    assert!(catch_unwind(AssertUnwindSafe(|| arena.reset())).is_err());
+    assert!(arena.is_empty());
+    assert_eq!(t.live(), 0);
+    arena.alloc(t.item(3));
+    arena.alloc(t.bomb(4));
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(arena))).is_err());
+    assert_eq!(t.live(), 0);
+
+    let mut queue = StaticSpsc::<_, 3>::new();
+    {
+        let (mut producer, mut consumer) = queue.split();
+        producer.push(t.item(0)).unwrap();
+        producer.push(t.bomb(1)).unwrap();
+        consumer.pop();
+        producer.push(t.item(2)).unwrap();
+        producer.push(t.item(3)).unwrap();
+    }
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(queue))).is_err());
+    assert_eq!(t.live(), 0);
+
+    let mut jagged = StaticJagged::<_, 6, 3>::new();
+    jagged.push_row_from_iter([t.item(0)]).unwrap();
+    jagged
+        .push_row_from_iter([t.item(1), t.bomb(2), t.item(3)])
+        .unwrap();
+    let row = jagged.pop_row().unwrap();
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(row))).is_err());
+    assert_eq!(t.live(), 1);
+    drop(jagged);
+    assert_eq!(t.live(), 0);
+
+    let mut buf: [MaybeUninit<Item>; 4] = core::array::from_fn(|_| MaybeUninit::uninit());
+    let mut v = SliceVec::from_uninit_slice(&mut buf);
+    v.push_array([t.item(0), t.bomb(1), t.item(2)]).unwrap();
+    assert!(catch_unwind(AssertUnwindSafe(|| v.into_uninit_slice())).is_err());
+    assert_eq!(t.live(), 0);
+}
+
+#[test]
+fn containers_panic_in_clone() {
+    let t = Tracker::new();
+
+    let mut jagged = StaticJagged::<_, 6, 3>::new();
+    jagged.push_row(&[t.item(0)]).unwrap();
+    let row = [t.item(1), t.item(2)];
+    t.panic_after_clones(1);
+    assert!(catch_unwind(AssertUnwindSafe(|| jagged.push_row(&row))).is_err());
+    assert_eq!(jagged.len(), 1);
+    assert_eq!(t.live(), 3);
+    t.clones_left.set(None);
+    jagged.push_row(&[t.item(9)]).unwrap();
+    let rows: Vec<_> = jagged.rows().map(values).collect();
+    assert_eq!(rows, [vec![0], vec![9]]);
+    drop((jagged, row));
+    assert_eq!(t.live(), 0);
+
+    let mut jagged = StaticJagged::<_, 6, 3>::new();
+    jagged.push_row_from_iter([t.item(0)]).unwrap();
+    let iter = (1..4).map(|i| if i < 3 { t.item(i) } else { panic!("next") });
+    assert!(catch_unwind(AssertUnwindSafe(|| jagged.push_row_from_iter(iter))).is_err());
+    assert_eq!(t.live(), 1);
+    jagged.push_row_from_iter([t.item(9)]).unwrap();
+    let rows: Vec<_> = jagged.rows().map(values).collect();
+    assert_eq!(rows, [vec![0], vec![9]]);
+    drop(jagged);
+    assert_eq!(t.live(), 0);
+}
@@ -2,7 +2,7 @@ use core::{
     convert::{AsMut, AsRef},
     mem::MaybeUninit,
 };
-use num_traits::{Bounded, FromPrimitive, NumAssign, ToPrimitive, Unsigned};
+use num_traits::{Bounded, FromPrimitive, NumAssign, PrimInt, ToPrimitive, Unsigned};
 
 /// Slot for `T` that may be empty or occupied.
 ///
@@ -45,22 +45,68 @@ impl<T> UninitSlot for MaybeUninit<T> {
     }
 }
 
-unsafe impl Slot for u8 {
-    type Item = u8;
+macro_rules! impl_zeroed_slot {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl Slot for $t {
+                type Item = $t;
 
-    fn new(byte: u8) -> Self {
-        byte
+                fn new(item: $t) -> Self {
+                    item
+                }
+                unsafe fn assume_init(self) -> Self::Item {
+                    self
+                }
+                unsafe fn assume_init_read(&self) -> Self::Item {
+                    *self
+                }
+            }
+            impl UninitSlot for $t {
+                fn uninit() -> Self {
+                    0 as $t
+                }
+            }
+        )*
+    };
+}
+
+// Primitive numbers are slots for themselves, empty slots are filled with zeros.
+//
+// Note that vectors of such slots are still not `Copy`, because `GenericVec` implements `Drop`.
+impl_zeroed_slot!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Slot for plain old data which is always initialized, empty slots are filled with zeros.
+///
+/// Allows to use any [`Pod`](`bytemuck::Pod`) type in fully-initialized containers, e.g. `[PodSlot<T>; N]`.
+///
+/// Vector over such container is not `Copy` anyway, because [`GenericVec`](`crate::GenericVec`) implements `Drop`.
+#[cfg(feature = "bytemuck")]
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct PodSlot<T: bytemuck::Pod>(pub T);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> Slot for PodSlot<T> {
+    type Item = T;
+
+    fn new(item: T) -> Self {
+        Self(item)
     }
     unsafe fn assume_init(self) -> Self::Item {
-        self
+        self.0
     }
     unsafe fn assume_init_read(&self) -> Self::Item {
-        *self
+        self.0
     }
 }
-impl UninitSlot for u8 {
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Zeroable for PodSlot<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for PodSlot<T> {}
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod> UninitSlot for PodSlot<T> {
     fn uninit() -> Self {
-        0
+        Self(T::zeroed())
     }
 }
 
@@ -96,7 +142,7 @@ unsafe impl<S: Slot> Container for [S] {
     type Slot = S;
 }
 
-unsafe impl<'a, S: Slot> Container for &'a mut [S] {
+unsafe impl<S: Slot> Container for &mut [S] {
     type Item = S::Item;
     type Slot = S;
 }
@@ -111,3 +157,8 @@ impl<T> Length for T where
     T: Unsigned + NumAssign + Copy + Sized + Ord + Bounded + ToPrimitive + FromPrimitive
 {
 }
+
+/// Unsigned integer that is used as a storage word in bit vectors.
+pub trait BitWord: PrimInt + Unsigned + UninitSlot + Slot<Item = Self> {}
+
+impl<T> BitWord for T where T: PrimInt + Unsigned + UninitSlot + Slot<Item = Self> {}
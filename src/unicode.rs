@@ -0,0 +1,84 @@
+use crate::{
+    traits::{Container, DefaultContainer, Length},
+    GenericString,
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Length in bytes of the longest prefix of whole grapheme clusters not exceeding `max_len` bytes and `max_width` columns.
+fn graphemes_prefix_len(s: &str, max_len: usize, max_width: usize) -> usize {
+    let mut len = 0;
+    let mut width = 0;
+    for grapheme in s.graphemes(true) {
+        width += grapheme.width();
+        if len + grapheme.len() > max_len || width > max_width {
+            break;
+        }
+        len += grapheme.len();
+    }
+    len
+}
+
+impl<C: DefaultContainer<Item = u8>, L: Length> GenericString<C, L> {
+    /// Creates a string from the longest prefix of `s` consisting of whole grapheme clusters that fits.
+    ///
+    /// Returns the string and the number of dropped bytes.
+    pub fn from_str_truncated_graphemes(s: &str) -> (Self, usize) {
+        let mut self_ = Self::default();
+        let dropped = self_.push_str_truncated_graphemes(s);
+        (self_, dropped)
+    }
+    /// Creates a string from the longest prefix of `s` consisting of whole grapheme clusters
+    /// that fits and takes no more than `width` columns when displayed.
+    ///
+    /// Returns the string and the number of dropped bytes.
+    pub fn from_str_truncated_width(s: &str, width: usize) -> (Self, usize) {
+        let mut self_ = Self::default();
+        let dropped = self_.push_str_truncated_width(s, width);
+        (self_, dropped)
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> GenericString<C, L> {
+    /// Display width of the string in columns.
+    pub fn width(&self) -> usize {
+        self.as_str().width()
+    }
+
+    /// Appends the longest prefix of `s` consisting of whole grapheme clusters that fits.
+    ///
+    /// Returns the number of dropped bytes.
+    pub fn push_str_truncated_graphemes(&mut self, s: &str) -> usize {
+        self.push_str_truncated_width(s, usize::MAX)
+    }
+
+    /// Appends the longest prefix of `s` consisting of whole grapheme clusters
+    /// that fits and takes no more than `width` columns when displayed.
+    ///
+    /// Returns the number of dropped bytes.
+    pub fn push_str_truncated_width(&mut self, s: &str, width: usize) -> usize {
+        let len = graphemes_prefix_len(s, self.remaining(), width);
+        self.push_str(&s[..len]).unwrap();
+        s.len() - len
+    }
+
+    /// Appends `s` if it fits into capacity and `width` columns,
+    /// otherwise appends the longest prefix of whole grapheme clusters followed by `marker` (e.g. `"…"`).
+    ///
+    /// If even the `marker` doesn't fit then it is truncated too.
+    /// Returns the number of dropped bytes of `s`.
+    pub fn push_str_truncated_width_with(&mut self, s: &str, width: usize, marker: &str) -> usize {
+        if s.len() <= self.remaining() && s.width() <= width {
+            self.push_str(s).unwrap();
+            return 0;
+        }
+        let len = graphemes_prefix_len(
+            s,
+            self.remaining().saturating_sub(marker.len()),
+            width.saturating_sub(marker.width()),
+        );
+        self.push_str(&s[..len]).unwrap();
+        self.push_str_truncated_width(marker, width.saturating_sub(s[..len].width()));
+        s.len() - len
+    }
+}
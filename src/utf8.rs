@@ -0,0 +1,175 @@
+use crate::{
+    error::FullError,
+    traits::{Container, DefaultContainer, Length},
+    GenericString,
+};
+use core::{
+    char::REPLACEMENT_CHARACTER,
+    str::{from_utf8, from_utf8_unchecked},
+};
+
+impl<C: DefaultContainer<Item = u8>, L: Length> GenericString<C, L> {
+    /// Creates a string from bytes replacing invalid UTF-8 sequences with [`REPLACEMENT_CHARACTER`].
+    ///
+    /// Fails if the result doesn't fit.
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Result<Self, FullError> {
+        let mut self_ = Self::default();
+        self_.push_utf8_lossy(bytes)?;
+        Ok(self_)
+    }
+}
+
+impl<C: Container<Item = u8> + ?Sized, L: Length> GenericString<C, L> {
+    /// Appends bytes replacing invalid UTF-8 sequences with [`REPLACEMENT_CHARACTER`].
+    ///
+    /// If the result doesn't fit then nothing is appended.
+    pub fn push_utf8_lossy(&mut self, bytes: &[u8]) -> Result<(), FullError> {
+        let len: usize = bytes
+            .utf8_chunks()
+            .map(|chunk| match chunk.invalid() {
+                [] => chunk.valid().len(),
+                _ => chunk.valid().len() + REPLACEMENT_CHARACTER.len_utf8(),
+            })
+            .sum();
+        if len > self.remaining() {
+            return Err(FullError);
+        }
+        for chunk in bytes.utf8_chunks() {
+            self.push_str(chunk.valid()).unwrap();
+            if !chunk.invalid().is_empty() {
+                self.push(REPLACEMENT_CHARACTER).unwrap();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Incremental UTF-8 decoder.
+///
+/// Accepts input by chunks, a multibyte character split between chunks is buffered until the next chunk.
+/// Invalid sequences are replaced with [`REPLACEMENT_CHARACTER`].
+#[derive(Clone, Default, Debug)]
+pub struct Utf8Decoder {
+    /// Incomplete sequence from the previous chunk.
+    buf: [u8; 4],
+    len: usize,
+}
+
+impl Utf8Decoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bytes of incomplete sequence waiting for the next chunk.
+    pub fn pending(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Decodes bytes appending characters to `out`.
+    ///
+    /// Returns the number of consumed bytes which is less than `bytes.len()` only if `out` is full.
+    /// In that case remaining bytes should be passed again when there is free space.
+    pub fn decode<C: Container<Item = u8> + ?Sized, L: Length>(
+        &mut self,
+        bytes: &[u8],
+        out: &mut GenericString<C, L>,
+    ) -> usize {
+        let mut pos = 0;
+        if self.len > 0 {
+            match self.complete(bytes, out) {
+                Some(n) => pos = n,
+                None => return 0,
+            }
+        }
+        while pos < bytes.len() {
+            let (valid, error) = match from_utf8(&bytes[pos..]) {
+                Ok(s) => (s, None),
+                Err(e) => (
+                    unsafe { from_utf8_unchecked(&bytes[pos..(pos + e.valid_up_to())]) },
+                    Some(e.error_len()),
+                ),
+            };
+            let n = valid.len() - out.push_str_truncated(valid);
+            pos += n;
+            if n < valid.len() {
+                break;
+            }
+            match error {
+                None => (),
+                Some(Some(len)) => {
+                    if out.push(REPLACEMENT_CHARACTER).is_err() {
+                        break;
+                    }
+                    pos += len;
+                }
+                Some(None) => {
+                    let tail = &bytes[pos..];
+                    self.buf[..tail.len()].copy_from_slice(tail);
+                    self.len = tail.len();
+                    pos = bytes.len();
+                }
+            }
+        }
+        pos
+    }
+
+    /// Completes the pending sequence with first bytes of the chunk.
+    ///
+    /// Returns the number of consumed bytes or `None` if `out` is full.
+    fn complete<C: Container<Item = u8> + ?Sized, L: Length>(
+        &mut self,
+        bytes: &[u8],
+        out: &mut GenericString<C, L>,
+    ) -> Option<usize> {
+        let mut seq = [0; 7];
+        let extra = bytes.len().min(seq.len() - self.len);
+        seq[..self.len].copy_from_slice(self.pending());
+        seq[self.len..(self.len + extra)].copy_from_slice(&bytes[..extra]);
+        let seq = &seq[..(self.len + extra)];
+        let seq_len = match from_utf8(seq) {
+            Ok(s) => s.chars().next().unwrap().len_utf8(),
+            Err(e) if e.valid_up_to() > 0 => {
+                unsafe { from_utf8_unchecked(&seq[..e.valid_up_to()]) }
+                    .chars()
+                    .next()
+                    .unwrap()
+                    .len_utf8()
+            }
+            Err(e) => match e.error_len() {
+                Some(len) => {
+                    // Invalid sequence consists of pending bytes only, the next byte will be decoded separately.
+                    out.push(REPLACEMENT_CHARACTER).ok()?;
+                    let consumed = len - self.len;
+                    self.len = 0;
+                    return Some(consumed);
+                }
+                None => {
+                    // Chunk is too short to complete the sequence.
+                    self.buf[..seq.len()].copy_from_slice(seq);
+                    self.len = seq.len();
+                    return Some(bytes.len());
+                }
+            },
+        };
+        out.push_str(unsafe { from_utf8_unchecked(&seq[..seq_len]) })
+            .ok()?;
+        let consumed = seq_len - self.len;
+        self.len = 0;
+        Some(consumed)
+    }
+
+    /// Finishes decoding.
+    ///
+    /// Incomplete pending sequence is replaced with [`REPLACEMENT_CHARACTER`].
+    /// If `out` is full then nothing happens and error is returned.
+    pub fn finish<C: Container<Item = u8> + ?Sized, L: Length>(
+        &mut self,
+        out: &mut GenericString<C, L>,
+    ) -> Result<(), FullError> {
+        if self.len > 0 {
+            out.push(REPLACEMENT_CHARACTER)?;
+            self.len = 0;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    error::{FullError, WideStringError},
+    traits::{Container, DefaultContainer, Length},
+    GenericString, GenericVec,
+};
+use core::{
+    char::decode_utf16,
+    cmp::Ordering,
+    fmt::{self, Write},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+fn check_utf16(units: &[u16]) -> Result<(), WideStringError> {
+    let mut index = 0;
+    for c in decode_utf16(units.iter().copied()) {
+        match c {
+            Ok(c) => index += c.len_utf16(),
+            Err(_) => return Err(WideStringError::UnpairedSurrogate(index)),
+        }
+    }
+    Ok(())
+}
+
+/// UTF-16 string.
+///
+/// Always contains valid UTF-16, i.e. there are no unpaired surrogates.
+#[repr(transparent)]
+pub struct GenericWideString<C: Container<Item = u16> + ?Sized, L: Length = usize> {
+    units: GenericVec<C, L>,
+}
+
+/// UTF-16 string with static capacity of `N` code units.
+pub type StaticWideString<const N: usize> = GenericWideString<[u16; N]>;
+
+impl<C: DefaultContainer<Item = u16>, L: Length> GenericWideString<C, L> {
+    /// Create a new empty string.
+    pub fn new() -> Self {
+        Self {
+            units: GenericVec::new(),
+        }
+    }
+
+    /// Creates a string from code units checking that they are valid UTF-16.
+    pub fn try_from_units(units: &[u16]) -> Result<Self, WideStringError> {
+        check_utf16(units)?;
+        let mut units_vec = GenericVec::new();
+        units_vec.push_slice(units)?;
+        Ok(Self { units: units_vec })
+    }
+}
+
+impl<C: DefaultContainer<Item = u16>, L: Length> Default for GenericWideString<C, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<C: DefaultContainer<Item = u16>, L: Length> Clone for GenericWideString<C, L> {
+    fn clone(&self) -> Self {
+        Self {
+            units: self.units.clone(),
+        }
+    }
+}
+
+impl<C: DefaultContainer<Item = u16>, L: Length> FromStr for GenericWideString<C, L> {
+    type Err = FullError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut self_ = Self::new();
+        self_.push_str(s)?;
+        Ok(self_)
+    }
+}
+impl<C: DefaultContainer<Item = u16>, L: Length> TryFrom<&str> for GenericWideString<C, L> {
+    type Error = FullError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl<C: DefaultContainer<Item = u16>, L: Length> TryFrom<&[u16]> for GenericWideString<C, L> {
+    type Error = WideStringError;
+
+    fn try_from(units: &[u16]) -> Result<Self, Self::Error> {
+        Self::try_from_units(units)
+    }
+}
+
+impl<C: Container<Item = u16>, L: Length> GenericWideString<C, L> {
+    /// Creates a string from the vector checking that it contains valid UTF-16.
+    pub fn try_from_vec(vec: GenericVec<C, L>) -> Result<Self, WideStringError> {
+        check_utf16(vec.as_slice())?;
+        Ok(Self { units: vec })
+    }
+    /// Converts the string into underlying vector.
+    pub fn into_units(self) -> GenericVec<C, L> {
+        self.units
+    }
+}
+
+impl<C: Container<Item = u16>, L: Length> TryFrom<GenericVec<C, L>> for GenericWideString<C, L> {
+    type Error = WideStringError;
+
+    fn try_from(vec: GenericVec<C, L>) -> Result<Self, Self::Error> {
+        Self::try_from_vec(vec)
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> GenericWideString<C, L> {
+    /// Maximum number of code units.
+    pub fn capacity(&self) -> usize {
+        self.units.capacity()
+    }
+    /// Number of code units.
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+    pub fn remaining(&self) -> usize {
+        self.units.remaining()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+    pub fn is_full(&self) -> bool {
+        self.units.is_full()
+    }
+
+    /// Provides an access to underlying vector.
+    pub fn as_vec(&self) -> &GenericVec<C, L> {
+        &self.units
+    }
+    pub fn as_units(&self) -> &[u16] {
+        self.units.as_slice()
+    }
+
+    /// Returns iterator over decoded characters.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        decode_utf16(self.as_units().iter().copied()).map(|c| c.unwrap())
+    }
+
+    /// Converts the string into UTF-8 string.
+    ///
+    /// Fails if the result doesn't fit into the UTF-8 string capacity.
+    pub fn try_to_string<D: DefaultContainer<Item = u8>, M: Length>(
+        &self,
+    ) -> Result<GenericString<D, M>, FullError> {
+        let mut string = GenericString::default();
+        self.chars().try_for_each(|c| string.push(c))?;
+        Ok(string)
+    }
+
+    pub fn clear(&mut self) {
+        self.units.clear();
+    }
+
+    /// Appends a character encoding it as one or two code units.
+    pub fn push(&mut self, c: char) -> Result<(), FullError> {
+        let mut units = [0; 2];
+        self.units.push_slice(c.encode_utf16(&mut units))
+    }
+    /// Appends a string slice.
+    ///
+    /// If the slice doesn't fit then nothing is appended.
+    pub fn push_str(&mut self, s: &str) -> Result<(), FullError> {
+        let len = self.units.len();
+        s.chars().try_for_each(|c| self.push(c)).inspect_err(|_| {
+            self.units.truncate(len);
+        })
+    }
+    /// Removes the last character and returns it.
+    pub fn pop(&mut self) -> Option<char> {
+        let last = *self.as_units().last()?;
+        // Low surrogate is always the second unit of a pair.
+        let start = self.len()
+            - if (0xdc00..0xe000).contains(&last) {
+                2
+            } else {
+                1
+            };
+        let c = decode_utf16(self.as_units()[start..].iter().copied()).next()?;
+        self.units.truncate(start);
+        c.ok()
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> Hash for GenericWideString<C, L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_units().hash(state)
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> fmt::Debug for GenericWideString<C, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_char('"')?;
+        for c in self.chars() {
+            match c {
+                '\'' => f.write_char(c)?,
+                c => c.escape_debug().try_for_each(|e| f.write_char(e))?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> fmt::Display for GenericWideString<C, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+/// Fails if the string doesn't fit, nothing is written in that case.
+impl<C: Container<Item = u16> + ?Sized, L: Length> fmt::Write for GenericWideString<C, L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|FullError| fmt::Error)
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> AsRef<[u16]> for GenericWideString<C, L> {
+    fn as_ref(&self) -> &[u16] {
+        self.as_units()
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> PartialEq<str> for GenericWideString<C, L> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_units().iter().copied().eq(other.encode_utf16())
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> PartialEq<&str> for GenericWideString<C, L> {
+    fn eq(&self, other: &&str) -> bool {
+        self.eq(*other)
+    }
+}
+
+impl<
+        C: Container<Item = u16> + ?Sized,
+        L: Length,
+        D: Container<Item = u16> + ?Sized,
+        M: Length,
+    > PartialEq<GenericWideString<D, M>> for GenericWideString<C, L>
+{
+    fn eq(&self, other: &GenericWideString<D, M>) -> bool {
+        self.as_units().eq(other.as_units())
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> Eq for GenericWideString<C, L> {}
+
+impl<
+        C: Container<Item = u16> + ?Sized,
+        L: Length,
+        D: Container<Item = u16> + ?Sized,
+        M: Length,
+    > PartialOrd<GenericWideString<D, M>> for GenericWideString<C, L>
+{
+    fn partial_cmp(&self, other: &GenericWideString<D, M>) -> Option<Ordering> {
+        self.as_units().partial_cmp(other.as_units())
+    }
+}
+
+impl<C: Container<Item = u16> + ?Sized, L: Length> Ord for GenericWideString<C, L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_units().cmp(other.as_units())
+    }
+}